@@ -0,0 +1,87 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use distrs::{gamma, Normal, StudentsT};
+
+fn normal(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Normal::cdf");
+    // center, near the erf/erfc switch, and far tail
+    for x in [0.5, 1.5, 8.0] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", x)),
+            &x,
+            |b, &x| b.iter(|| Normal::cdf(black_box(x), 0.0, 1.0)),
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("Normal::ppf");
+    // central region and both tail regions of AS 241
+    for p in [0.5, 0.01, 1e-20] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", p)),
+            &p,
+            |b, &p| b.iter(|| Normal::ppf(black_box(p), 0.0, 1.0)),
+        );
+    }
+    group.finish();
+}
+
+fn students_t(c: &mut Criterion) {
+    let mut group = c.benchmark_group("StudentsT::cdf");
+    // both continued fractions, non-integer n, and the large n asymptotic
+    for (x, n) in [(0.2, 3.0), (1.0, 3.0), (10.0, 3.0), (1.0, 2.5), (1.0, 5e4)] {
+        let id = format!("x={:?},n={:?}", x, n);
+        group.bench_with_input(BenchmarkId::from_parameter(id), &(x, n), |b, &(x, n)| {
+            b.iter(|| StudentsT::cdf(black_box(x), black_box(n)))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("StudentsT::cdf_hill");
+    // incomplete beta, cosine series, tail series, closed form, and asymptotic branches
+    for (x, n) in [
+        (1.0, 0.5),
+        (1.0, 3.0),
+        (10.0, 3.0),
+        (1.0, 4.0),
+        (1.0, 2.5),
+        (1.0, 50.0),
+    ] {
+        let id = format!("x={:?},n={:?}", x, n);
+        group.bench_with_input(BenchmarkId::from_parameter(id), &(x, n), |b, &(x, n)| {
+            b.iter(|| StudentsT::cdf_hill(black_box(x), black_box(n)))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("StudentsT::ppf");
+    // below one, exact forms, small n, large n, and the extreme tail
+    for (p, n) in [
+        (0.9, 0.5),
+        (0.9, 2.0),
+        (0.9, 3.0),
+        (0.9, 50.0),
+        (1e-20, 3.0),
+    ] {
+        let id = format!("p={:?},n={:?}", p, n);
+        group.bench_with_input(BenchmarkId::from_parameter(id), &(p, n), |b, &(p, n)| {
+            b.iter(|| StudentsT::ppf(black_box(p), black_box(n)))
+        });
+    }
+    group.finish();
+}
+
+fn gamma_calculate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gamma::calculate");
+    // reflection, small, and large arguments
+    for x in [-2.5, 0.5, 7.5, 150.0] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", x)),
+            &x,
+            |b, &x| b.iter(|| gamma::calculate(black_box(x))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, normal, students_t, gamma_calculate);
+criterion_main!(benches);
@@ -0,0 +1,230 @@
+use crate::math::{asin, log, sin, sqrt};
+use crate::ContinuousDistribution;
+use core::f64::consts::PI;
+
+/// The arcsine distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arcsine {
+    a: f64,
+    b: f64,
+}
+
+impl Arcsine {
+    /// Creates an arcsine distribution with the given bounds.
+    ///
+    /// Returns `None` if `a` or `b` is not finite or `b` is not greater than `a`.
+    pub fn new(a: f64, b: f64) -> Option<Self> {
+        if !a.is_finite() || !b.is_finite() || b <= a {
+            return None;
+        }
+
+        Some(Arcsine { a, b })
+    }
+
+    /// Returns the probability density function (PDF) of the arcsine distribution.
+    pub fn pdf(x: f64, a: f64, b: f64) -> f64 {
+        if x.is_nan() || a.is_nan() || b.is_nan() || b <= a {
+            return f64::NAN;
+        }
+
+        if x < a || x > b {
+            return 0.0;
+        }
+
+        // density is unbounded at the endpoints
+        if x == a || x == b {
+            return f64::INFINITY;
+        }
+
+        1.0 / (PI * sqrt((x - a) * (b - x)))
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the arcsine distribution.
+    pub fn cdf(x: f64, a: f64, b: f64) -> f64 {
+        if x.is_nan() || a.is_nan() || b.is_nan() || b <= a {
+            return f64::NAN;
+        }
+
+        if x <= a {
+            return 0.0;
+        }
+
+        if x >= b {
+            return 1.0;
+        }
+
+        2.0 / PI * asin(sqrt((x - a) / (b - a)))
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the arcsine distribution.
+    pub fn ppf(p: f64, a: f64, b: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) || a.is_nan() || b.is_nan() || b <= a {
+            return f64::NAN;
+        }
+
+        let s = sin(PI / 2.0 * p);
+        a + (b - a) * s * s
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the arcsine distribution.
+    ///
+    /// Alias for [`Arcsine::ppf`].
+    pub fn quantile(p: f64, a: f64, b: f64) -> f64 {
+        Arcsine::ppf(p, a, b)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the arcsine distribution.
+    ///
+    /// Alias for [`Arcsine::ppf`].
+    pub fn inv_cdf(p: f64, a: f64, b: f64) -> f64 {
+        Arcsine::ppf(p, a, b)
+    }
+}
+
+impl ContinuousDistribution for Arcsine {
+    fn pdf(&self, x: f64) -> f64 {
+        Arcsine::pdf(x, self.a, self.b)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        Arcsine::cdf(x, self.a, self.b)
+    }
+
+    fn ppf(&self, p: f64) -> f64 {
+        Arcsine::ppf(p, self.a, self.b)
+    }
+
+    // quadrature converges slowly at the singularities of the density
+    fn entropy(&self) -> f64 {
+        log(PI * (self.b - self.a) / 4.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arcsine;
+
+    const INFINITY: f64 = f64::INFINITY;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_pdf() {
+        let inputs = [-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0, 2.0];
+        let expected = [
+            0.0, INFINITY, 1.06103, 0.73511, 0.63662, 0.73511, 1.06103, INFINITY, 0.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Arcsine::pdf(*input, 0.0, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_a_b() {
+        let inputs = [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0];
+        let expected = [0.0, INFINITY, 0.18378, 0.15915, 0.18378, INFINITY, 0.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Arcsine::pdf(*input, -2.0, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_nan() {
+        assert!(Arcsine::pdf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Arcsine::pdf(0.5, f64::NAN, 1.0).is_nan());
+        assert!(Arcsine::pdf(0.5, 0.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_invalid_bounds() {
+        assert!(Arcsine::pdf(0.5, 1.0, 1.0).is_nan());
+        assert!(Arcsine::pdf(0.5, 1.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0, 2.0];
+        let expected = [0.0, 0.0, 0.20483, 0.33333, 0.5, 0.66667, 0.79517, 1.0, 1.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Arcsine::cdf(*input, 0.0, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_a_b() {
+        let inputs = [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0];
+        let expected = [0.0, 0.0, 0.33333, 0.5, 0.66667, 1.0, 1.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Arcsine::cdf(*input, -2.0, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(Arcsine::cdf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Arcsine::cdf(0.5, f64::NAN, 1.0).is_nan());
+        assert!(Arcsine::cdf(0.5, 0.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_invalid_bounds() {
+        assert!(Arcsine::cdf(0.5, 1.0, 1.0).is_nan());
+        assert!(Arcsine::cdf(0.5, 1.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf() {
+        let inputs = [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0];
+        let expected = [0.0, 0.02447, 0.14645, 0.5, 0.85355, 0.97553, 1.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Arcsine::ppf(*input, 0.0, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_ppf_a_b() {
+        let inputs = [0.0, 0.5, 1.0];
+        let expected = [-2.0, 0.0, 2.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Arcsine::ppf(*input, -2.0, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_ppf_nan() {
+        assert!(Arcsine::ppf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Arcsine::ppf(0.5, f64::NAN, 1.0).is_nan());
+        assert!(Arcsine::ppf(0.5, 0.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_invalid() {
+        assert!(Arcsine::ppf(-0.1, 0.0, 1.0).is_nan());
+        assert!(Arcsine::ppf(1.1, 0.0, 1.0).is_nan());
+        assert!(Arcsine::ppf(0.5, 1.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_aliases() {
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(Arcsine::quantile(p, -2.0, 2.0), Arcsine::ppf(p, -2.0, 2.0));
+            assert_eq!(Arcsine::inv_cdf(p, -2.0, 2.0), Arcsine::ppf(p, -2.0, 2.0));
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Arcsine::new(-2.0, 2.0).is_some());
+        assert!(Arcsine::new(1.0, 1.0).is_none());
+        assert!(Arcsine::new(1.0, 0.0).is_none());
+        assert!(Arcsine::new(f64::NAN, 1.0).is_none());
+        assert!(Arcsine::new(0.0, f64::INFINITY).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::IrwinHall;
+
+/// The Bates distribution, the mean of `n` independent standard uniform random variables.
+pub struct Bates;
+
+impl Bates {
+    /// Returns the probability density function (PDF) of the Bates distribution.
+    pub fn pdf(x: f64, n: u32) -> f64 {
+        if x.is_nan() || n == 0 {
+            return f64::NAN;
+        }
+
+        let nf = n as f64;
+        nf * IrwinHall::pdf(nf * x, n)
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the Bates distribution.
+    pub fn cdf(x: f64, n: u32) -> f64 {
+        if x.is_nan() || n == 0 {
+            return f64::NAN;
+        }
+
+        IrwinHall::cdf(n as f64 * x, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Bates;
+    use crate::IrwinHall;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let inputs = [-0.5, 0.0, 0.25, 0.5, 0.75, 1.0, 1.5];
+        let expected = [0.0, 0.0, 1.0, 2.0, 1.0, 0.0, 0.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Bates::pdf(*input, 2), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_scaled_irwin_hall() {
+        for n in [1, 3, 5, 8] {
+            for i in 0..=20 {
+                let x = -0.1 + 0.06 * i as f64;
+                let nf = n as f64;
+                assert_in_delta(
+                    Bates::pdf(x, n),
+                    nf * IrwinHall::pdf(nf * x, n),
+                    0.000000001,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pdf_nan() {
+        assert!(Bates::pdf(f64::NAN, 3).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_zero_n() {
+        assert!(Bates::pdf(0.5, 0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [-0.5, 0.0, 0.25, 0.5, 0.75, 1.0, 1.5];
+        let expected = [0.0, 0.0, 0.125, 0.5, 0.875, 1.0, 1.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Bates::cdf(*input, 2), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(Bates::cdf(f64::NAN, 3).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_zero_n() {
+        assert!(Bates::cdf(0.5, 0).is_nan());
+    }
+}
@@ -0,0 +1,710 @@
+//! The beta distribution and beta functions.
+
+pub use crate::gamma::RegOpts;
+
+use crate::gamma::ln_gamma_sign;
+use crate::math::{exp, fabs, log, log1p, pow};
+use crate::stats::{fit_by_moments, Family, Params};
+use crate::{ContinuousDistribution, FitMoments};
+
+/// The beta distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Beta {
+    alpha: f64,
+    beta: f64,
+}
+
+impl Beta {
+    /// Creates a beta distribution with the given shape parameters.
+    ///
+    /// Returns `None` if `alpha` or `beta` is not positive and finite.
+    pub fn new(alpha: f64, beta: f64) -> Option<Self> {
+        if !alpha.is_finite() || !beta.is_finite() || alpha <= 0.0 || beta <= 0.0 {
+            return None;
+        }
+
+        Some(Beta { alpha, beta })
+    }
+
+    /// Returns the probability density function (PDF) of the beta distribution.
+    pub fn pdf(x: f64, alpha: f64, beta: f64) -> f64 {
+        if x.is_nan() || alpha.is_nan() || beta.is_nan() || alpha <= 0.0 || beta <= 0.0 {
+            return f64::NAN;
+        }
+
+        if !(0.0..=1.0).contains(&x) {
+            return 0.0;
+        }
+
+        // log form is undefined at the endpoints when alpha or beta is one
+        if x == 0.0 || x == 1.0 {
+            return pow(x, alpha - 1.0) * pow(1.0 - x, beta - 1.0)
+                / exp(ln_beta_positive(alpha, beta));
+        }
+
+        exp((alpha - 1.0) * log(x) + (beta - 1.0) * log(1.0 - x) - ln_beta_positive(alpha, beta))
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the beta distribution.
+    pub fn cdf(x: f64, alpha: f64, beta: f64) -> f64 {
+        if x.is_nan() || alpha.is_nan() || beta.is_nan() || alpha <= 0.0 || beta <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        if x >= 1.0 {
+            return 1.0;
+        }
+
+        incomplete_beta(x, alpha, beta, RegOpts::default()).unwrap_or(f64::NAN)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the beta distribution.
+    pub fn ppf(p: f64, alpha: f64, beta: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p)
+            || alpha.is_nan()
+            || beta.is_nan()
+            || alpha <= 0.0
+            || beta <= 0.0
+        {
+            return f64::NAN;
+        }
+
+        if p == 0.0 {
+            return 0.0;
+        }
+
+        if p == 1.0 {
+            return 1.0;
+        }
+
+        // start from the leading term of the nearer tail, since the mean
+        // can be far from the root when p is close to 0 or 1
+        let ln_b = ln_beta_positive(alpha, beta);
+        let mean = alpha / (alpha + beta);
+        let lower = exp((log(p) + log(alpha) + ln_b) / alpha);
+        let upper = 1.0 - exp((log1p(-p) + log(beta) + ln_b) / beta);
+        let mut x = if lower > 0.0 && lower < mean {
+            lower
+        } else if upper > mean && upper < 1.0 {
+            upper
+        } else {
+            mean
+        };
+
+        // Newton's method, falling back to bisection when a step leaves the bracket
+        let mut lo = 0.0;
+        let mut hi = 1.0;
+        for _ in 0..1000 {
+            let cdf = Beta::cdf(x, alpha, beta);
+            if cdf.is_nan() {
+                return f64::NAN;
+            }
+
+            if cdf == p {
+                return x;
+            }
+
+            if cdf < p {
+                lo = x;
+            } else {
+                hi = x;
+            }
+
+            let mut next = x - (cdf - p) / Beta::pdf(x, alpha, beta);
+            if !(next > lo && next < hi) {
+                next = 0.5 * (lo + hi);
+            }
+
+            if fabs(next - x) <= 4.0 * f64::EPSILON * x || next == lo || next == hi {
+                return next;
+            }
+            x = next;
+        }
+        x
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the beta distribution.
+    ///
+    /// Alias for [`Beta::ppf`].
+    pub fn quantile(p: f64, alpha: f64, beta: f64) -> f64 {
+        Beta::ppf(p, alpha, beta)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the beta distribution.
+    ///
+    /// Alias for [`Beta::ppf`].
+    pub fn inv_cdf(p: f64, alpha: f64, beta: f64) -> f64 {
+        Beta::ppf(p, alpha, beta)
+    }
+}
+
+impl ContinuousDistribution for Beta {
+    fn pdf(&self, x: f64) -> f64 {
+        Beta::pdf(x, self.alpha, self.beta)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        Beta::cdf(x, self.alpha, self.beta)
+    }
+
+    fn ppf(&self, p: f64) -> f64 {
+        Beta::ppf(p, self.alpha, self.beta)
+    }
+}
+
+impl FitMoments for Beta {
+    fn fit_moments(data: &[f64]) -> Option<Self> {
+        match fit_by_moments(Family::Beta, data) {
+            Params::Beta { alpha, beta } => Beta::new(alpha, beta),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<f64> for Beta {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        use crate::sample::standard_gamma;
+
+        loop {
+            let g1 = standard_gamma(self.alpha, rng);
+            let g2 = standard_gamma(self.beta, rng);
+
+            // both draws can underflow for tiny shape parameters
+            if g1 + g2 > 0.0 {
+                return g1 / (g1 + g2);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rand-distr-compat")]
+impl core::convert::TryFrom<Beta> for rand_distr::Beta<f64> {
+    type Error = rand_distr::BetaError;
+
+    fn try_from(beta: Beta) -> Result<Self, Self::Error> {
+        rand_distr::Beta::new(beta.alpha, beta.beta)
+    }
+}
+
+/// Returns the beta function B(a, b).
+///
+/// Returns `None` if the gamma function is undefined for `a` or `b`.
+pub fn calculate(a: f64, b: f64) -> Option<f64> {
+    let (value, sign) = ln_beta_sign(a, b)?;
+    Some(sign * exp(value))
+}
+
+/// Returns the natural logarithm of the absolute value of the beta function B(a, b).
+///
+/// Returns `None` if the gamma function is undefined for `a` or `b`.
+pub fn ln_beta(a: f64, b: f64) -> Option<f64> {
+    ln_beta_sign(a, b).map(|(value, _)| value)
+}
+
+/// Returns the regularized incomplete beta function I<sub>x</sub>(a, b).
+///
+/// Returns `None` if `a` or `b` is not positive, `x` is outside `[0, 1]`, or it fails to converge.
+pub fn regularized(x: f64, a: f64, b: f64) -> Option<f64> {
+    regularized_with(x, a, b, RegOpts::default())
+}
+
+/// Returns the regularized incomplete beta function I<sub>x</sub>(a, b) with the given options.
+///
+/// Returns `None` if `a` or `b` is not positive, `x` is outside `[0, 1]`, or it fails to converge within `opts.max_iter` iterations.
+pub fn regularized_with(x: f64, a: f64, b: f64, opts: RegOpts) -> Option<f64> {
+    if !(0.0..=1.0).contains(&x) || a.is_nan() || b.is_nan() || a <= 0.0 || b <= 0.0 {
+        return None;
+    }
+
+    if x == 0.0 || x == 1.0 {
+        return Some(x);
+    }
+
+    incomplete_beta(x, a, b, opts)
+}
+
+fn ln_beta_sign(a: f64, b: f64) -> Option<(f64, f64)> {
+    let (ln_a, sign_a) = ln_gamma_sign(a);
+    let (ln_b, sign_b) = ln_gamma_sign(b);
+    if sign_a.is_nan() || sign_b.is_nan() || sign_a == 0.0 || sign_b == 0.0 {
+        return None;
+    }
+
+    // beta function is zero when a + b is a pole
+    let (ln_ab, sign_ab) = ln_gamma_sign(a + b);
+    Some((ln_a + ln_b - ln_ab, sign_a * sign_b * sign_ab))
+}
+
+fn ln_beta_positive(a: f64, b: f64) -> f64 {
+    ln_gamma_sign(a).0 + ln_gamma_sign(b).0 - ln_gamma_sign(a + b).0
+}
+
+// regularized incomplete beta function I_x(a, b) for 0 < x < 1
+fn incomplete_beta(x: f64, a: f64, b: f64, opts: RegOpts) -> Option<f64> {
+    let front = exp(a * log(x) + b * log(1.0 - x) - ln_beta_positive(a, b));
+
+    // continued fraction converges rapidly for x < (a + 1) / (a + b + 2)
+    // otherwise use symmetry relation I_x(a, b) = 1 - I_{1-x}(b, a)
+    if x < (a + 1.0) / (a + b + 2.0) {
+        continued_fraction(x, a, b, opts).map(|h| front * h / a)
+    } else {
+        continued_fraction(1.0 - x, b, a, opts).map(|h| 1.0 - front * h / b)
+    }
+}
+
+// Lentz, W. J. (1976).
+// Generating Bessel functions in Mie scattering calculations using continued fractions.
+// Applied Optics, 15(3), 668-671.
+pub(crate) fn continued_fraction(x: f64, a: f64, b: f64, opts: RegOpts) -> Option<f64> {
+    const TINY: f64 = 1e-300;
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if fabs(d) < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..=opts.max_iter {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        // even step
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if fabs(d) < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if fabs(c) < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+
+        // odd step
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if fabs(d) < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if fabs(c) < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+
+        if fabs(delta - 1.0) < opts.eps {
+            return Some(h);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{calculate, ln_beta, regularized, regularized_with, Beta, RegOpts};
+    use crate::FitMoments;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let inputs = [-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0, 2.0];
+        let expected = [0.0, 0.0, 0.54, 1.125, 1.5, 1.125, 0.54, 0.0, 0.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Beta::pdf(*input, 2.0, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_asymmetric() {
+        let inputs = [0.1, 0.25, 0.5, 0.75, 0.9];
+        let expected = [1.9683, 2.37305, 0.9375, 0.08789, 0.0027];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Beta::pdf(*input, 2.0, 5.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_pdf_less_than_one() {
+        let inputs = [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0];
+        let expected = [
+            f64::INFINITY,
+            1.06103,
+            0.73511,
+            0.63662,
+            0.73511,
+            1.06103,
+            f64::INFINITY,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Beta::pdf(*input, 0.5, 0.5), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_one() {
+        assert_in_delta(Beta::pdf(0.0, 1.0, 1.0), 1.0, 0.00001);
+        assert_in_delta(Beta::pdf(0.5, 1.0, 1.0), 1.0, 0.00001);
+        assert_in_delta(Beta::pdf(1.0, 1.0, 1.0), 1.0, 0.00001);
+    }
+
+    #[test]
+    fn test_pdf_nan() {
+        assert!(Beta::pdf(f64::NAN, 2.0, 2.0).is_nan());
+        assert!(Beta::pdf(0.5, f64::NAN, 2.0).is_nan());
+        assert!(Beta::pdf(0.5, 2.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_non_positive() {
+        assert!(Beta::pdf(0.5, 0.0, 2.0).is_nan());
+        assert!(Beta::pdf(0.5, 2.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [-1.0, 0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0, 2.0];
+        let expected = [0.0, 0.0, 0.028, 0.15625, 0.5, 0.84375, 0.972, 1.0, 1.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Beta::cdf(*input, 2.0, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_asymmetric() {
+        let inputs = [0.1, 0.25, 0.5, 0.75, 0.9];
+        let expected = [0.11427, 0.46606, 0.89063, 0.99536, 0.99995];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Beta::cdf(*input, 2.0, 5.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_less_than_one() {
+        let inputs = [0.1, 0.25, 0.5, 0.75, 0.9];
+        let expected = [0.20483, 0.33333, 0.5, 0.66667, 0.79517];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Beta::cdf(*input, 0.5, 0.5), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_symmetry() {
+        assert_in_delta(Beta::cdf(0.5, 2.0, 2.0), 0.5, 0.0000000001);
+        assert_in_delta(Beta::cdf(0.5, 50.0, 50.0), 0.5, 0.0000000001);
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(Beta::cdf(f64::NAN, 2.0, 2.0).is_nan());
+        assert!(Beta::cdf(0.5, f64::NAN, 2.0).is_nan());
+        assert!(Beta::cdf(0.5, 2.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_non_positive() {
+        assert!(Beta::cdf(0.5, 0.0, 2.0).is_nan());
+        assert!(Beta::cdf(0.5, 2.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf() {
+        let inputs = [0.0, 0.1, 0.5, 0.9, 1.0];
+        let expected = [
+            0.0,
+            0.14255931671003072,
+            0.38572756813238956,
+            0.6795394162781817,
+            1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Beta::ppf(*input, 2.0, 3.0), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_ppf_less_than_one() {
+        assert_in_delta(Beta::ppf(0.5, 0.5, 0.5), 0.5, 0.000000000001);
+        assert_in_delta(
+            Beta::ppf(0.01, 0.5, 2.0),
+            4.444576138515904e-05,
+            0.000000000001,
+        );
+        assert_in_delta(
+            Beta::ppf(0.99, 5.0, 0.5),
+            0.9999834873051512,
+            0.000000000001,
+        );
+        assert_in_delta(
+            Beta::ppf(0.3, 0.2, 7.0),
+            0.00024032522824386822,
+            0.000000000001,
+        );
+    }
+
+    #[test]
+    fn test_ppf_tail() {
+        let act = Beta::ppf(1e-10, 2.0, 5.0);
+        assert!((act / 2.581997786411184e-06 - 1.0).abs() < 1e-10, "{}", act);
+        let act = Beta::ppf(1e-100, 0.5, 0.5);
+        assert!(
+            (act / 2.4674011002723397e-200 - 1.0).abs() < 1e-10,
+            "{}",
+            act
+        );
+    }
+
+    #[test]
+    fn test_ppf_cdf() {
+        for (alpha, beta) in [
+            (0.5, 0.5),
+            (1.0, 1.0),
+            (2.0, 3.0),
+            (8.0, 3.0),
+            (81.0, 183.0),
+        ] {
+            for p in [0.001, 0.025, 0.3, 0.5, 0.7, 0.975, 0.999] {
+                assert_in_delta(
+                    Beta::cdf(Beta::ppf(p, alpha, beta), alpha, beta),
+                    p,
+                    0.000000000001,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ppf_invalid() {
+        assert!(Beta::ppf(-0.1, 2.0, 3.0).is_nan());
+        assert!(Beta::ppf(1.1, 2.0, 3.0).is_nan());
+        assert!(Beta::ppf(f64::NAN, 2.0, 3.0).is_nan());
+        assert!(Beta::ppf(0.5, 0.0, 3.0).is_nan());
+        assert!(Beta::ppf(0.5, 2.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_aliases() {
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(Beta::quantile(p, 2.0, 3.0), Beta::ppf(p, 2.0, 3.0));
+            assert_eq!(Beta::inv_cdf(p, 2.0, 3.0), Beta::ppf(p, 2.0, 3.0));
+        }
+    }
+
+    #[test]
+    fn test_regularized() {
+        let inputs = [0.0, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0];
+        let expected = [
+            0.0, 0.114265, 0.46606445, 0.890625, 0.99536133, 0.999945, 1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(regularized(*input, 2.0, 5.0).unwrap(), exp, 0.00000001);
+        }
+    }
+
+    #[test]
+    fn test_regularized_symmetry() {
+        for x in [0.05, 0.3, 0.5, 0.7, 0.95] {
+            let lower = regularized(x, 3.5, 1.5).unwrap();
+            let upper = regularized(1.0 - x, 1.5, 3.5).unwrap();
+            assert_in_delta(lower + upper, 1.0, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_regularized_large() {
+        assert_in_delta(regularized(0.5, 1000.0, 1000.0).unwrap(), 0.5, 0.000000001);
+        assert_in_delta(
+            regularized(0.48, 1000.0, 1000.0).unwrap(),
+            0.03677948,
+            0.0000001,
+        );
+    }
+
+    #[test]
+    fn test_regularized_invalid() {
+        assert_eq!(regularized(-0.1, 2.0, 2.0), None);
+        assert_eq!(regularized(1.1, 2.0, 2.0), None);
+        assert_eq!(regularized(0.5, 0.0, 2.0), None);
+        assert_eq!(regularized(0.5, 2.0, -1.0), None);
+        assert_eq!(regularized(f64::NAN, 2.0, 2.0), None);
+        assert_eq!(regularized(0.5, f64::NAN, 2.0), None);
+    }
+
+    #[test]
+    fn test_calculate() {
+        assert_in_delta(calculate(2.0, 3.0).unwrap(), 1.0 / 12.0, 0.000000000001);
+        assert_in_delta(
+            calculate(0.5, 0.5).unwrap(),
+            core::f64::consts::PI,
+            0.000000000001,
+        );
+        assert_in_delta(calculate(-0.5, 2.0).unwrap(), -4.0, 0.000000000001);
+        assert_eq!(calculate(-0.5, -1.5), Some(0.0));
+    }
+
+    #[test]
+    fn test_calculate_undefined() {
+        assert_eq!(calculate(0.0, 1.0), None);
+        assert_eq!(calculate(1.0, -2.0), None);
+        assert_eq!(calculate(f64::NAN, 1.0), None);
+    }
+
+    #[test]
+    fn test_ln_beta() {
+        assert_in_delta(ln_beta(0.5, 0.5).unwrap(), 1.1447298858494, 0.000000000001);
+        assert_in_delta(
+            ln_beta(50.0, 50.0).unwrap(),
+            -70.00271747688563,
+            0.00000000001,
+        );
+        assert_in_delta(
+            ln_beta(1000.0, 2000.0).unwrap(),
+            -1911.8746142144516,
+            0.0000000001,
+        );
+        assert_in_delta(
+            ln_beta(-0.5, 2.0).unwrap(),
+            1.3862943611198906,
+            0.000000000001,
+        );
+    }
+
+    #[test]
+    fn test_ln_beta_undefined() {
+        assert_eq!(ln_beta(0.0, 1.0), None);
+        assert_eq!(ln_beta(1.0, -2.0), None);
+        assert_eq!(ln_beta(1.0, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Beta::new(2.0, 3.0).is_some());
+        assert!(Beta::new(0.0, 3.0).is_none());
+        assert!(Beta::new(2.0, -1.0).is_none());
+        assert!(Beta::new(f64::NAN, 3.0).is_none());
+        assert!(Beta::new(2.0, f64::INFINITY).is_none());
+    }
+
+    #[test]
+    fn test_fit_moments() {
+        let mut data = [0.0; 1000];
+        for (i, x) in data.iter_mut().enumerate() {
+            *x = Beta::ppf((i as f64 + 0.5) / 1000.0, 2.0, 5.0);
+        }
+        let beta = Beta::fit_moments(&data).unwrap();
+        assert_in_delta(beta.alpha, 2.0, 0.05);
+        assert_in_delta(beta.beta, 5.0, 0.05);
+    }
+
+    #[test]
+    fn test_fit_moments_degenerate() {
+        assert!(Beta::fit_moments(&[]).is_none());
+        assert!(Beta::fit_moments(&[0.5, 0.5]).is_none());
+        assert!(Beta::fit_moments(&[0.0, 1.0]).is_none());
+        assert!(Beta::fit_moments(&[0.5, 1.5]).is_none());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        for (alpha, beta) in [(0.5, 0.5), (2.0, 5.0), (10.0, 1.0)] {
+            let dist = Beta::new(alpha, beta).unwrap();
+            let n = 100000;
+            let mut sum = 0.0;
+            for _ in 0..n {
+                let x = dist.sample(&mut rng);
+                assert!((0.0..=1.0).contains(&x));
+                sum += x;
+            }
+            assert_in_delta(sum / n as f64, alpha / (alpha + beta), 0.005);
+        }
+    }
+
+    #[test]
+    fn test_regularized_with_default() {
+        for (x, a, b) in [(0.2, 2.0, 3.0), (0.7, 0.5, 0.5), (0.5, 1e5, 1e5)] {
+            assert_eq!(
+                regularized_with(x, a, b, RegOpts::default()),
+                regularized(x, a, b)
+            );
+        }
+    }
+
+    #[test]
+    fn test_regularized_with_eps() {
+        let opts = RegOpts {
+            eps: 1e-6,
+            ..RegOpts::default()
+        };
+        let act = regularized_with(0.3, 20.0, 30.0, opts).unwrap();
+        assert_in_delta(act, regularized(0.3, 20.0, 30.0).unwrap(), 1e-5);
+    }
+
+    #[test]
+    fn test_regularized_with_max_iter() {
+        let opts = RegOpts {
+            max_iter: 10,
+            ..RegOpts::default()
+        };
+        assert_eq!(regularized_with(0.499, 1e5, 1e5, opts), None);
+
+        let opts = RegOpts {
+            max_iter: 0,
+            ..RegOpts::default()
+        };
+        assert_eq!(regularized_with(0.3, 2.0, 3.0, opts), None);
+    }
+
+    #[test]
+    fn test_regularized_with_bounds() {
+        let opts = RegOpts {
+            max_iter: 0,
+            ..RegOpts::default()
+        };
+        assert_eq!(regularized_with(0.0, 2.0, 3.0, opts), Some(0.0));
+        assert_eq!(regularized_with(1.0, 2.0, 3.0, opts), Some(1.0));
+        assert_eq!(regularized_with(0.5, 0.0, 3.0, opts), None);
+    }
+
+    #[cfg(feature = "rand-distr-compat")]
+    #[test]
+    fn test_rand_distr() {
+        use core::convert::TryFrom;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rand_distr::Distribution;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = rand_distr::Beta::try_from(Beta::new(2.0, 5.0).unwrap()).unwrap();
+        let n = 100000;
+        let mean = (0..n).map(|_| dist.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert_in_delta(mean, 2.0 / 7.0, 0.005);
+    }
+}
@@ -0,0 +1,776 @@
+use crate::beta::regularized;
+use crate::discrete::seeded_ppf;
+use crate::gamma::ln_gamma_sign;
+use crate::math::{exp, floor, log, log1p, sqrt};
+use crate::{Beta, Normal};
+
+/// The binomial distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Binomial {
+    n: u32,
+    p: f64,
+}
+
+impl Binomial {
+    /// Creates a binomial distribution with the given number of trials and success probability.
+    ///
+    /// Returns `None` if `n` is zero or `p` is outside `[0, 1]`.
+    pub fn new(n: u32, p: f64) -> Option<Self> {
+        if n == 0 || !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+
+        Some(Binomial { n, p })
+    }
+
+    /// Returns the probability mass function (PMF) of the binomial distribution.
+    pub fn pmf<T: Into<f64>>(k: T, n: u32, p: f64) -> f64 {
+        let k = k.into();
+
+        if k.is_nan() || !(0.0..=1.0).contains(&p) || n == 0 {
+            return f64::NAN;
+        }
+
+        let nf = n as f64;
+        if !(0.0..=nf).contains(&k) || k != floor(k) {
+            return 0.0;
+        }
+
+        // avoid 0 * -inf at the ends
+        if p == 0.0 {
+            return if k == 0.0 { 1.0 } else { 0.0 };
+        }
+        if p == 1.0 {
+            return if k == nf { 1.0 } else { 0.0 };
+        }
+
+        // binomial coefficient in log space to avoid overflow for large n
+        let ln_choose =
+            ln_gamma_sign(nf + 1.0).0 - ln_gamma_sign(k + 1.0).0 - ln_gamma_sign(nf - k + 1.0).0;
+        exp(ln_choose + k * log(p) + (nf - k) * log1p(-p))
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the binomial distribution.
+    pub fn cdf<T: Into<f64>>(k: T, n: u32, p: f64) -> f64 {
+        let k = k.into();
+
+        if k.is_nan() || !(0.0..=1.0).contains(&p) || n == 0 {
+            return f64::NAN;
+        }
+
+        if k < 0.0 {
+            return 0.0;
+        }
+
+        let nf = n as f64;
+        if k >= nf {
+            return 1.0;
+        }
+
+        let k = floor(k);
+        regularized(1.0 - p, nf - k, k + 1.0).unwrap_or(f64::NAN)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the binomial distribution.
+    ///
+    /// This is the smallest `k` where the CDF is at least `q`.
+    pub fn ppf(q: f64, n: u32, p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&q) || !(0.0..=1.0).contains(&p) || n == 0 {
+            return f64::NAN;
+        }
+
+        let nf = n as f64;
+        if q == 0.0 || p == 0.0 {
+            return 0.0;
+        }
+
+        if q == 1.0 || p == 1.0 {
+            return nf;
+        }
+
+        let mean = nf * p;
+        let std_dev = sqrt(mean * (1.0 - p));
+        seeded_ppf(q, mean, std_dev, (1.0 - 2.0 * p) / std_dev, nf, |k| {
+            Binomial::cdf(k, n, p)
+        })
+    }
+
+    /// Returns the lower and upper bounds of the Wald confidence interval for a proportion.
+    ///
+    /// This is the normal approximation, which has poor coverage for small `n` or proportions near 0 or 1. The bounds are clamped to `[0, 1]`. Returns `NAN` values if `n` is zero, `successes` is greater than `n`, or `confidence` is not between 0 and 1.
+    pub fn wald_interval(successes: u64, n: u64, confidence: f64) -> (f64, f64) {
+        if n == 0 || successes > n || !(confidence > 0.0 && confidence < 1.0) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let nf = n as f64;
+        let p = successes as f64 / nf;
+        let z = Normal::std_ppf(0.5 + 0.5 * confidence);
+        let half_width = z * sqrt(p * (1.0 - p) / nf);
+        ((p - half_width).max(0.0), (p + half_width).min(1.0))
+    }
+
+    /// Returns the lower and upper bounds of the Wilson score confidence interval for a proportion.
+    ///
+    /// Returns `NAN` values if `n` is zero, `successes` is greater than `n`, or `confidence` is not between 0 and 1.
+    pub fn wilson_interval(successes: u64, n: u64, confidence: f64) -> (f64, f64) {
+        if n == 0 || successes > n || !(confidence > 0.0 && confidence < 1.0) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let nf = n as f64;
+        let p = successes as f64 / nf;
+        let z = Normal::std_ppf(0.5 + 0.5 * confidence);
+        let z2 = z * z;
+        let denom = 1.0 + z2 / nf;
+        let center = (p + z2 / (2.0 * nf)) / denom;
+        let half_width = z / denom * sqrt(p * (1.0 - p) / nf + z2 / (4.0 * nf * nf));
+        // the bounds are exactly 0 and 1 at the ends, which rounding would miss
+        let lower = if successes == 0 {
+            0.0
+        } else {
+            center - half_width
+        };
+        let upper = if successes == n {
+            1.0
+        } else {
+            center + half_width
+        };
+        (lower, upper)
+    }
+
+    /// Returns the lower and upper bounds of the Agresti-Coull confidence interval for a proportion.
+    ///
+    /// This is the Wald interval with `z^2 / 2` successes and failures added. The bounds are clamped to `[0, 1]`. Returns `NAN` values if `n` is zero, `successes` is greater than `n`, or `confidence` is not between 0 and 1.
+    pub fn agresti_coull(successes: u64, n: u64, confidence: f64) -> (f64, f64) {
+        if n == 0 || successes > n || !(confidence > 0.0 && confidence < 1.0) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let z = Normal::std_ppf(0.5 + 0.5 * confidence);
+        let z2 = z * z;
+        let n_adj = n as f64 + z2;
+        let p_adj = (successes as f64 + 0.5 * z2) / n_adj;
+        let half_width = z * sqrt(p_adj * (1.0 - p_adj) / n_adj);
+        ((p_adj - half_width).max(0.0), (p_adj + half_width).min(1.0))
+    }
+
+    /// Returns the lower and upper bounds of the Clopper-Pearson exact confidence interval for a proportion.
+    ///
+    /// Returns `NAN` values if `n` is zero, `successes` is greater than `n`, or `confidence` is not between 0 and 1.
+    pub fn clopper_pearson(successes: u64, n: u64, confidence: f64) -> (f64, f64) {
+        if n == 0 || successes > n || !(confidence > 0.0 && confidence < 1.0) {
+            return (f64::NAN, f64::NAN);
+        }
+
+        // quantiles of the beta distributions are undefined for a zero shape parameter
+        let k = successes as f64;
+        let nf = n as f64;
+        let alpha = 1.0 - confidence;
+        let lower = if successes == 0 {
+            0.0
+        } else {
+            Beta::ppf(0.5 * alpha, k, nf - k + 1.0)
+        };
+        let upper = if successes == n {
+            1.0
+        } else {
+            Beta::ppf(1.0 - 0.5 * alpha, k + 1.0, nf - k)
+        };
+        (lower, upper)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<u64> for Binomial {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        if self.p == 0.0 {
+            return 0;
+        }
+
+        if self.p == 1.0 {
+            return self.n as u64;
+        }
+
+        // sample the count of the less likely outcome
+        let r = self.p.min(1.0 - self.p);
+        let nf = self.n as f64;
+        let y = if nf * r < 30.0 {
+            sample_inversion(nf, r, rng)
+        } else {
+            sample_btpe(nf, r, rng)
+        };
+
+        if self.p > 0.5 {
+            self.n as u64 - y
+        } else {
+            y
+        }
+    }
+}
+
+#[cfg(feature = "rand-distr-compat")]
+impl core::convert::TryFrom<Binomial> for rand_distr::Binomial {
+    type Error = rand_distr::BinomialError;
+
+    fn try_from(binomial: Binomial) -> Result<Self, Self::Error> {
+        rand_distr::Binomial::new(binomial.n as u64, binomial.p)
+    }
+}
+
+// sequential search from zero, restarting if the search runs far into the tail
+#[cfg(feature = "rand")]
+fn sample_inversion<R: rand::Rng + ?Sized>(n: f64, p: f64, rng: &mut R) -> u64 {
+    let q = 1.0 - p;
+    let q_n = exp(n * log1p(-p));
+    let bound = n.min(n * p + 10.0 * sqrt(n * p * q + 1.0));
+
+    let mut x = 0.0;
+    let mut px = q_n;
+    let mut u = rng.gen::<f64>();
+    while u > px {
+        x += 1.0;
+        if x > bound {
+            x = 0.0;
+            px = q_n;
+            u = rng.gen::<f64>();
+        } else {
+            u -= px;
+            px *= (n - x + 1.0) * p / (x * q);
+        }
+    }
+    x as u64
+}
+
+// Kachitvichyanukul, V., & Schmeiser, B. W. (1988).
+// Binomial random variate generation.
+// Communications of the ACM, 31(2), 216-222.
+#[cfg(feature = "rand")]
+#[allow(clippy::excessive_precision)]
+fn sample_btpe<R: rand::Rng + ?Sized>(n: f64, p: f64, rng: &mut R) -> u64 {
+    use crate::math::fabs;
+
+    let q = 1.0 - p;
+    let npq = n * p * q;
+    let f_m = n * p + p;
+    let m = floor(f_m);
+
+    // triangle, parallelograms, and exponential tails of the hat
+    let p1 = floor(2.195 * sqrt(npq) - 4.6 * q) + 0.5;
+    let x_m = m + 0.5;
+    let x_l = x_m - p1;
+    let x_r = x_m + p1;
+    let c = 0.134 + 20.5 / (15.3 + m);
+    let a = (f_m - x_l) / (f_m - x_l * p);
+    let lambda_l = a * (1.0 + 0.5 * a);
+    let a = (x_r - f_m) / (x_r * q);
+    let lambda_r = a * (1.0 + 0.5 * a);
+    let p2 = p1 * (1.0 + 2.0 * c);
+    let p3 = p2 + c / lambda_l;
+    let p4 = p3 + c / lambda_r;
+
+    loop {
+        let u = rng.gen::<f64>() * p4;
+        let mut v = rng.gen::<f64>();
+
+        let y = if u <= p1 {
+            // triangular region is always accepted
+            return floor(x_m - p1 * v + u) as u64;
+        } else if u <= p2 {
+            let x = x_l + (u - p1) / c;
+            v = v * c + 1.0 - fabs(m - x + 0.5) / p1;
+            if v > 1.0 {
+                continue;
+            }
+            floor(x)
+        } else if u <= p3 {
+            let y = floor(x_l + log(v) / lambda_l);
+            if y < 0.0 {
+                continue;
+            }
+            v *= (u - p2) * lambda_l;
+            y
+        } else {
+            let y = floor(x_r - log(v) / lambda_r);
+            if y > n {
+                continue;
+            }
+            v *= (u - p3) * lambda_r;
+            y
+        };
+
+        let k = fabs(y - m);
+        if k <= 20.0 || k >= 0.5 * npq - 1.0 {
+            // evaluate the ratio of pmf values recursively
+            let s = p / q;
+            let a = s * (n + 1.0);
+            let mut f = 1.0;
+            if m < y {
+                let mut i = m + 1.0;
+                while i <= y {
+                    f *= a / i - s;
+                    i += 1.0;
+                }
+            } else if m > y {
+                let mut i = y + 1.0;
+                while i <= m {
+                    f /= a / i - s;
+                    i += 1.0;
+                }
+            }
+
+            if v > f {
+                continue;
+            }
+            return y as u64;
+        }
+
+        // squeeze using bounds on the log of the pmf ratio
+        let rho = (k / npq) * ((k * (k / 3.0 + 0.625) + 0.16666666666666666) / npq + 0.5);
+        let t = -k * k / (2.0 * npq);
+        let ln_v = log(v);
+        if ln_v < t - rho {
+            return y as u64;
+        }
+        if ln_v > t + rho {
+            continue;
+        }
+
+        // final comparison with Stirling's approximation of the pmf ratio
+        let x1 = y + 1.0;
+        let f1 = m + 1.0;
+        let z = n + 1.0 - m;
+        let w = n - y + 1.0;
+        let stirling = |x: f64| {
+            let x2 = x * x;
+            (13680.0 - (462.0 - (132.0 - (99.0 - 140.0 / x2) / x2) / x2) / x2) / x / 166320.0
+        };
+        let bound = x_m * log(f1 / x1)
+            + (n - m + 0.5) * log(z / w)
+            + (y - m) * log(w * p / (x1 * q))
+            + stirling(f1)
+            + stirling(z)
+            + stirling(x1)
+            + stirling(w);
+        if ln_v > bound {
+            continue;
+        }
+        return y as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Binomial;
+    #[cfg(feature = "rand")]
+    use crate::ChiSquared;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pmf() {
+        let expected = [
+            0.00098, 0.00977, 0.04395, 0.11719, 0.20508, 0.24609, 0.20508, 0.11719, 0.04395,
+            0.00977, 0.00098,
+        ];
+        for (k, exp) in expected.iter().enumerate() {
+            assert_in_delta(Binomial::pmf(k as u32, 10, 0.5), *exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pmf_skewed() {
+        let inputs = [0, 2, 4, 6, 8, 10, 12];
+        let expected = [0.0008, 0.02785, 0.13042, 0.19164, 0.1144, 0.03082, 0.00386];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Binomial::pmf(*input, 20, 0.3), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pmf_outside_support() {
+        assert_eq!(Binomial::pmf(-1, 10, 0.5), 0.0);
+        assert_eq!(Binomial::pmf(11, 10, 0.5), 0.0);
+        assert_eq!(Binomial::pmf(2.5, 10, 0.5), 0.0);
+    }
+
+    #[test]
+    fn test_pmf_large_n() {
+        assert_in_delta(
+            Binomial::pmf(500, 1000, 0.5),
+            0.0252250181783608,
+            0.000000000001,
+        );
+    }
+
+    #[test]
+    fn test_pmf_degenerate_p() {
+        assert_eq!(Binomial::pmf(0, 10, 0.0), 1.0);
+        assert_eq!(Binomial::pmf(1, 10, 0.0), 0.0);
+        assert_eq!(Binomial::pmf(10, 10, 1.0), 1.0);
+        assert_eq!(Binomial::pmf(9, 10, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_pmf_invalid() {
+        assert!(Binomial::pmf(f64::NAN, 10, 0.5).is_nan());
+        assert!(Binomial::pmf(1, 0, 0.5).is_nan());
+        assert!(Binomial::pmf(1, 10, -0.1).is_nan());
+        assert!(Binomial::pmf(1, 10, 1.1).is_nan());
+        assert!(Binomial::pmf(1, 10, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let expected = [
+            0.00098, 0.01074, 0.05469, 0.17188, 0.37695, 0.62305, 0.82812, 0.94531, 0.98926,
+            0.99902, 1.0,
+        ];
+        for (k, exp) in expected.iter().enumerate() {
+            assert_in_delta(Binomial::cdf(k as u32, 10, 0.5), *exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_skewed() {
+        let inputs = [0.0, 2.0, 4.0, 6.0, 8.0, 8.5, 10.0, 12.0];
+        let expected = [
+            0.0008, 0.03548, 0.23751, 0.60801, 0.88667, 0.88667, 0.98286, 0.99872,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Binomial::cdf(*input, 20, 0.3), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_outside_support() {
+        assert_eq!(Binomial::cdf(-1, 10, 0.5), 0.0);
+        assert_eq!(Binomial::cdf(10, 10, 0.5), 1.0);
+        assert_eq!(Binomial::cdf(f64::INFINITY, 10, 0.5), 1.0);
+    }
+
+    #[test]
+    fn test_cdf_degenerate_p() {
+        assert_eq!(Binomial::cdf(0, 10, 0.0), 1.0);
+        assert_eq!(Binomial::cdf(9, 10, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_cdf_invalid() {
+        assert!(Binomial::cdf(f64::NAN, 10, 0.5).is_nan());
+        assert!(Binomial::cdf(1, 0, 0.5).is_nan());
+        assert!(Binomial::cdf(1, 10, -0.1).is_nan());
+        assert!(Binomial::cdf(1, 10, 1.1).is_nan());
+    }
+
+    #[test]
+    fn test_ppf() {
+        let inputs = [0.0, 0.001, 0.05, 0.5, 0.95, 0.999, 1.0];
+        let expected = [0.0, 1.0, 2.0, 5.0, 8.0, 9.0, 10.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_eq!(Binomial::ppf(*input, 10, 0.5), exp);
+        }
+    }
+
+    #[test]
+    fn test_ppf_large_n() {
+        // matches a linear scan from zero
+        let n = 100000;
+        for p in [0.001, 0.3, 0.999] {
+            for q in [0.001, 0.1, 0.5, 0.9, 0.999] {
+                let mut k = 0.0;
+                while Binomial::cdf(k, n, p) < q {
+                    k += 1.0;
+                }
+                assert_eq!(Binomial::ppf(q, n, p), k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ppf_degenerate_p() {
+        assert_eq!(Binomial::ppf(0.5, 10, 0.0), 0.0);
+        assert_eq!(Binomial::ppf(0.5, 10, 1.0), 10.0);
+    }
+
+    #[test]
+    fn test_ppf_invalid() {
+        assert!(Binomial::ppf(-0.1, 10, 0.5).is_nan());
+        assert!(Binomial::ppf(1.1, 10, 0.5).is_nan());
+        assert!(Binomial::ppf(f64::NAN, 10, 0.5).is_nan());
+        assert!(Binomial::ppf(0.5, 0, 0.5).is_nan());
+        assert!(Binomial::ppf(0.5, 10, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_wilson_interval() {
+        let (lower, upper) = Binomial::wilson_interval(8, 10, 0.95);
+        assert_in_delta(lower, 0.4901624715366418, 0.000000000001);
+        assert_in_delta(upper, 0.9433178485456247, 0.000000000001);
+
+        let (lower, upper) = Binomial::wilson_interval(81, 263, 0.95);
+        assert_in_delta(lower, 0.2552885198782742, 0.000000000001);
+        assert_in_delta(upper, 0.36620957698280004, 0.000000000001);
+
+        let (lower, upper) = Binomial::wilson_interval(1, 50, 0.99);
+        assert_in_delta(lower, 0.002352480614833343, 0.000000000001);
+        assert_in_delta(upper, 0.1501135274340895, 0.000000000001);
+    }
+
+    #[test]
+    fn test_wilson_interval_boundary() {
+        let (lower, upper) = Binomial::wilson_interval(0, 10, 0.95);
+        assert_eq!(lower, 0.0);
+        assert_in_delta(upper, 0.2775327998628892, 0.000000000001);
+
+        let (lower, upper) = Binomial::wilson_interval(10, 10, 0.95);
+        assert_in_delta(lower, 0.7224672001371109, 0.000000000001);
+        assert_eq!(upper, 1.0);
+    }
+
+    #[test]
+    fn test_wilson_interval_invalid() {
+        for (successes, n, confidence) in [
+            (1, 0, 0.95),
+            (11, 10, 0.95),
+            (5, 10, 0.0),
+            (5, 10, 1.0),
+            (5, 10, f64::NAN),
+        ] {
+            let (lower, upper) = Binomial::wilson_interval(successes, n, confidence);
+            assert!(lower.is_nan());
+            assert!(upper.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_wald_interval() {
+        let (lower, upper) = Binomial::wald_interval(81, 263, 0.95);
+        assert_in_delta(lower, 0.2521901262131072, 0.000000000001);
+        assert_in_delta(upper, 0.36377945553594226, 0.000000000001);
+
+        let (lower, upper) = Binomial::wald_interval(8, 10, 0.95);
+        assert_in_delta(lower, 0.5520819870781754, 0.000000000001);
+        assert_eq!(upper, 1.0);
+    }
+
+    #[test]
+    fn test_wald_interval_boundary() {
+        assert_eq!(Binomial::wald_interval(0, 10, 0.95), (0.0, 0.0));
+        assert_eq!(Binomial::wald_interval(10, 10, 0.95), (1.0, 1.0));
+    }
+
+    #[test]
+    fn test_wald_interval_invalid() {
+        for (successes, n, confidence) in [
+            (1, 0, 0.95),
+            (11, 10, 0.95),
+            (5, 10, 0.0),
+            (5, 10, 1.0),
+            (5, 10, f64::NAN),
+        ] {
+            let (lower, upper) = Binomial::wald_interval(successes, n, confidence);
+            assert!(lower.is_nan());
+            assert!(upper.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_agresti_coull() {
+        let (lower, upper) = Binomial::agresti_coull(8, 10, 0.95);
+        assert_in_delta(lower, 0.4793675905661507, 0.000000000001);
+        assert_in_delta(upper, 0.9541127295161158, 0.000000000001);
+
+        let (lower, upper) = Binomial::agresti_coull(81, 263, 0.95);
+        assert_in_delta(lower, 0.2552206651899968, 0.000000000001);
+        assert_in_delta(upper, 0.3662774316710775, 0.000000000001);
+
+        let (lower, upper) = Binomial::agresti_coull(1, 50, 0.99);
+        assert_eq!(lower, 0.0);
+        assert_in_delta(upper, 0.16706267928089422, 0.000000000001);
+    }
+
+    #[test]
+    fn test_agresti_coull_boundary() {
+        let (lower, upper) = Binomial::agresti_coull(0, 10, 0.95);
+        assert_eq!(lower, 0.0);
+        assert_in_delta(upper, 0.3208873057505457, 0.000000000001);
+
+        let (lower, upper) = Binomial::agresti_coull(10, 10, 0.95);
+        assert_in_delta(lower, 0.6791126942494543, 0.000000000001);
+        assert_eq!(upper, 1.0);
+    }
+
+    #[test]
+    fn test_agresti_coull_contains_wilson() {
+        for (successes, n) in [(1, 10), (8, 10), (81, 263), (500, 1000)] {
+            let adjusted = Binomial::agresti_coull(successes, n, 0.95);
+            let wilson = Binomial::wilson_interval(successes, n, 0.95);
+            assert!(adjusted.0 <= wilson.0 && wilson.1 <= adjusted.1);
+        }
+    }
+
+    #[test]
+    fn test_agresti_coull_invalid() {
+        for (successes, n, confidence) in [
+            (1, 0, 0.95),
+            (11, 10, 0.95),
+            (5, 10, 0.0),
+            (5, 10, 1.0),
+            (5, 10, f64::NAN),
+        ] {
+            let (lower, upper) = Binomial::agresti_coull(successes, n, confidence);
+            assert!(lower.is_nan());
+            assert!(upper.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_clopper_pearson() {
+        let (lower, upper) = Binomial::clopper_pearson(8, 10, 0.95);
+        assert_in_delta(lower, 0.44390453769235844, 0.000000000001);
+        assert_in_delta(upper, 0.9747892736731666, 0.000000000001);
+
+        let (lower, upper) = Binomial::clopper_pearson(81, 263, 0.95);
+        assert_in_delta(lower, 0.25273674558527115, 0.000000000001);
+        assert_in_delta(upper, 0.36762192260135135, 0.000000000001);
+
+        let (lower, upper) = Binomial::clopper_pearson(1, 50, 0.99);
+        assert_in_delta(lower, 0.00010024581152369888, 0.000000000001);
+        assert_in_delta(upper, 0.1394041245610722, 0.000000000001);
+    }
+
+    #[test]
+    fn test_clopper_pearson_boundary() {
+        let (lower, upper) = Binomial::clopper_pearson(0, 10, 0.95);
+        assert_eq!(lower, 0.0);
+        assert_in_delta(upper, 0.3084971078187608, 0.000000000001);
+
+        let (lower, upper) = Binomial::clopper_pearson(10, 10, 0.95);
+        assert_in_delta(lower, 0.6915028921812392, 0.000000000001);
+        assert_eq!(upper, 1.0);
+    }
+
+    #[test]
+    fn test_clopper_pearson_contains_wilson() {
+        for (successes, n) in [(1, 10), (8, 10), (81, 263), (500, 1000)] {
+            let exact = Binomial::clopper_pearson(successes, n, 0.95);
+            let wilson = Binomial::wilson_interval(successes, n, 0.95);
+            assert!(exact.0 <= wilson.0 && wilson.1 <= exact.1);
+        }
+    }
+
+    #[test]
+    fn test_clopper_pearson_invalid() {
+        for (successes, n, confidence) in [
+            (1, 0, 0.95),
+            (11, 10, 0.95),
+            (5, 10, 0.0),
+            (5, 10, 1.0),
+            (5, 10, f64::NAN),
+        ] {
+            let (lower, upper) = Binomial::clopper_pearson(successes, n, confidence);
+            assert!(lower.is_nan());
+            assert!(upper.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Binomial::new(10, 0.5).is_some());
+        assert!(Binomial::new(10, 0.0).is_some());
+        assert!(Binomial::new(0, 0.5).is_none());
+        assert!(Binomial::new(10, -0.1).is_none());
+        assert!(Binomial::new(10, 1.1).is_none());
+        assert!(Binomial::new(10, f64::NAN).is_none());
+    }
+
+    // returns the p-value of a chi-squared goodness-of-fit test of samples against the pmf
+    #[cfg(feature = "rand")]
+    fn goodness_of_fit(n: u32, p: f64) -> f64 {
+        use rand::distributions::Distribution;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = Binomial::new(n, p).unwrap();
+        let samples = 200000;
+        let mut counts = [0; 1001];
+        for _ in 0..samples {
+            counts[dist.sample(&mut rng) as usize] += 1;
+        }
+
+        // pool adjacent values until each bin has an expected count of at least 5
+        let mut stat = 0.0;
+        let mut bins = 0;
+        let mut last = (0.0, 0.0);
+        let mut current = (0.0, 0.0);
+        for (k, count) in counts.iter().enumerate().take(n as usize + 1) {
+            current.0 += *count as f64;
+            current.1 += samples as f64 * Binomial::pmf(k as u32, n, p);
+            if current.1 >= 5.0 {
+                if bins > 0 {
+                    stat += (last.0 - last.1) * (last.0 - last.1) / last.1;
+                }
+                last = current;
+                current = (0.0, 0.0);
+                bins += 1;
+            }
+        }
+        // remaining upper tail joins the last bin
+        last.0 += current.0;
+        last.1 += current.1;
+        stat += (last.0 - last.1) * (last.0 - last.1) / last.1;
+
+        1.0 - ChiSquared::cdf(stat, bins - 1)
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_inversion() {
+        assert!(goodness_of_fit(20, 0.3) > 0.001);
+        assert!(goodness_of_fit(100, 0.05) > 0.001);
+        assert!(goodness_of_fit(20, 0.9) > 0.001);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_btpe() {
+        assert!(goodness_of_fit(1000, 0.4) > 0.001);
+        assert!(goodness_of_fit(200, 0.8) > 0.001);
+        assert!(goodness_of_fit(100, 0.5) > 0.001);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_degenerate() {
+        use rand::distributions::Distribution;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        assert_eq!(Binomial::new(10, 0.0).unwrap().sample(&mut rng), 0);
+        assert_eq!(Binomial::new(10, 1.0).unwrap().sample(&mut rng), 10);
+    }
+
+    #[cfg(feature = "rand-distr-compat")]
+    #[test]
+    fn test_rand_distr() {
+        use core::convert::TryFrom;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rand_distr::Distribution;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = rand_distr::Binomial::try_from(Binomial::new(20, 0.3).unwrap()).unwrap();
+        let n = 100000;
+        let mean = (0..n).map(|_| dist.sample(&mut rng) as f64).sum::<f64>() / n as f64;
+        assert_in_delta(mean, 6.0, 0.05);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::math::{atan, tan};
+use crate::ContinuousDistribution;
+use core::f64::consts::PI;
+
+/// The Cauchy distribution.
+///
+/// The standard Cauchy distribution is the Student's t distribution with one degree of freedom.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cauchy {
+    x0: f64,
+    gamma: f64,
+}
+
+impl Cauchy {
+    /// Creates a Cauchy distribution with the given location and scale.
+    ///
+    /// Returns `None` if `x0` is not finite or `gamma` is not positive and finite.
+    pub fn new(x0: f64, gamma: f64) -> Option<Self> {
+        if !x0.is_finite() || !gamma.is_finite() || gamma <= 0.0 {
+            return None;
+        }
+
+        Some(Cauchy { x0, gamma })
+    }
+
+    /// Returns the probability density function (PDF) of the Cauchy distribution.
+    pub fn pdf(x: f64, x0: f64, gamma: f64) -> f64 {
+        if x.is_nan() || x0.is_nan() || gamma.is_nan() || gamma <= 0.0 {
+            return f64::NAN;
+        }
+
+        let z = (x - x0) / gamma;
+        1.0 / (PI * gamma * (1.0 + z * z))
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the Cauchy distribution.
+    pub fn cdf(x: f64, x0: f64, gamma: f64) -> f64 {
+        if x.is_nan() || x0.is_nan() || gamma.is_nan() || gamma <= 0.0 {
+            return f64::NAN;
+        }
+
+        let z = (x - x0) / gamma;
+
+        // avoid cancellation in the lower tail
+        if z < -1.0 {
+            return -atan(1.0 / z) / PI;
+        }
+
+        0.5 + atan(z) / PI
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Cauchy distribution.
+    pub fn ppf(p: f64, x0: f64, gamma: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) || x0.is_nan() || gamma.is_nan() || gamma <= 0.0 {
+            return f64::NAN;
+        }
+
+        if p == 0.0 {
+            return f64::NEG_INFINITY;
+        }
+
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+
+        x0 + gamma * tan(PI * (p - 0.5))
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Cauchy distribution.
+    ///
+    /// Alias for [`Cauchy::ppf`].
+    pub fn quantile(p: f64, x0: f64, gamma: f64) -> f64 {
+        Cauchy::ppf(p, x0, gamma)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Cauchy distribution.
+    ///
+    /// Alias for [`Cauchy::ppf`].
+    pub fn inv_cdf(p: f64, x0: f64, gamma: f64) -> f64 {
+        Cauchy::ppf(p, x0, gamma)
+    }
+}
+
+impl ContinuousDistribution for Cauchy {
+    fn pdf(&self, x: f64) -> f64 {
+        Cauchy::pdf(x, self.x0, self.gamma)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        Cauchy::cdf(x, self.x0, self.gamma)
+    }
+
+    fn ppf(&self, p: f64) -> f64 {
+        Cauchy::ppf(p, self.x0, self.gamma)
+    }
+}
+
+#[cfg(feature = "rand-distr-compat")]
+impl core::convert::TryFrom<Cauchy> for rand_distr::Cauchy<f64> {
+    type Error = rand_distr::CauchyError;
+
+    fn try_from(cauchy: Cauchy) -> Result<Self, Self::Error> {
+        rand_distr::Cauchy::new(cauchy.x0, cauchy.gamma)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cauchy;
+    use crate::StudentsT;
+
+    const INFINITY: f64 = f64::INFINITY;
+    const NEG_INFINITY: f64 = f64::NEG_INFINITY;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let inputs = [NEG_INFINITY, -5.0, -1.0, 0.0, 1.0, 2.0, 5.0, INFINITY];
+        let expected = [
+            0.0,
+            0.015915494309189534,
+            0.07957747154594767,
+            0.12732395447351627,
+            0.15915494309189535,
+            0.12732395447351627,
+            0.03183098861837907,
+            0.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Cauchy::pdf(*input, 1.0, 2.0), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_nan() {
+        assert!(Cauchy::pdf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Cauchy::pdf(0.0, f64::NAN, 1.0).is_nan());
+        assert!(Cauchy::pdf(0.0, 0.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_non_positive_gamma() {
+        assert!(Cauchy::pdf(0.0, 0.0, 0.0).is_nan());
+        assert!(Cauchy::pdf(0.0, 0.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [NEG_INFINITY, -5.0, -1.0, 0.0, 1.0, 2.0, 5.0, INFINITY];
+        let expected = [
+            0.0,
+            0.10241638234956674,
+            0.25,
+            0.35241638234956674,
+            0.5,
+            0.6475836176504333,
+            0.8524163823495667,
+            1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Cauchy::cdf(*input, 1.0, 2.0), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_lower_tail() {
+        let act = Cauchy::cdf(-1e10, 0.0, 1.0);
+        assert!((act - 3.1830988618379065e-11).abs() < 1e-24);
+    }
+
+    #[test]
+    fn test_cdf_students_t() {
+        for x in [-100.0, -3.0, -1.0, -0.5, 0.0, 0.5, 1.0, 3.0, 100.0] {
+            assert_in_delta(Cauchy::cdf(x, 0.0, 1.0), StudentsT::cdf(x, 1), 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(Cauchy::cdf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Cauchy::cdf(0.0, 0.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf() {
+        let inputs = [0.0, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 1.0];
+        let expected = [
+            NEG_INFINITY,
+            -62.641031907547706,
+            -5.155367074350505,
+            -1.0,
+            1.0,
+            3.0,
+            7.155367074350505,
+            64.6410319075477,
+            INFINITY,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Cauchy::ppf(*input, 1.0, 2.0), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_ppf_round_trip() {
+        for p in [0.001, 0.1, 0.3, 0.5, 0.7, 0.9, 0.999] {
+            assert_in_delta(
+                Cauchy::cdf(Cauchy::ppf(p, -2.0, 0.5), -2.0, 0.5),
+                p,
+                0.000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_ppf_invalid() {
+        assert!(Cauchy::ppf(-0.1, 0.0, 1.0).is_nan());
+        assert!(Cauchy::ppf(1.1, 0.0, 1.0).is_nan());
+        assert!(Cauchy::ppf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Cauchy::ppf(0.5, 0.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_aliases() {
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(Cauchy::quantile(p, 0.0, 1.0), Cauchy::ppf(p, 0.0, 1.0));
+            assert_eq!(Cauchy::inv_cdf(p, 0.0, 1.0), Cauchy::ppf(p, 0.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Cauchy::new(1.0, 2.0).is_some());
+        assert!(Cauchy::new(1.0, 0.0).is_none());
+        assert!(Cauchy::new(1.0, -1.0).is_none());
+        assert!(Cauchy::new(f64::NAN, 2.0).is_none());
+        assert!(Cauchy::new(1.0, f64::INFINITY).is_none());
+    }
+
+    #[cfg(feature = "rand-distr-compat")]
+    #[test]
+    fn test_rand_distr() {
+        use core::convert::TryFrom;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rand_distr::Distribution;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = rand_distr::Cauchy::try_from(Cauchy::new(1.0, 2.0).unwrap()).unwrap();
+        let n = 100000;
+        // the mean is undefined, so check the quartiles instead
+        let mut counts = [0; 3];
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            for (count, q) in counts.iter_mut().zip([-1.0, 1.0, 3.0]) {
+                if x < q {
+                    *count += 1;
+                }
+            }
+        }
+        for (count, exp) in counts.iter().zip([0.25, 0.5, 0.75]) {
+            assert_in_delta(*count as f64 / n as f64, exp, 0.01);
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use crate::gamma::{ln_gamma_sign, lower_regularized};
+use crate::math::{exp, log, sqrt};
+use core::f64::consts::LN_2;
+
+/// The chi-squared distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChiSquared {
+    k: f64,
+}
+
+impl ChiSquared {
+    /// Creates a chi-squared distribution with the given degrees of freedom.
+    ///
+    /// Returns `None` if `k` is not positive and finite.
+    pub fn new<T: Into<f64>>(k: T) -> Option<Self> {
+        let k = k.into();
+
+        if !k.is_finite() || k <= 0.0 {
+            return None;
+        }
+
+        Some(ChiSquared { k })
+    }
+
+    /// Returns the probability density function (PDF) of the chi-squared distribution.
+    pub fn pdf<T: Into<f64>>(x: f64, k: T) -> f64 {
+        let k = k.into();
+
+        if x.is_nan() || k.is_nan() || k <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x < 0.0 || x == f64::INFINITY {
+            return 0.0;
+        }
+
+        if x == 0.0 {
+            return if k < 2.0 {
+                f64::INFINITY
+            } else if k == 2.0 {
+                0.5
+            } else {
+                0.0
+            };
+        }
+
+        let h = 0.5 * k;
+        exp((h - 1.0) * log(x) - 0.5 * x - h * LN_2 - ln_gamma_sign(h).0)
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the chi-squared distribution.
+    pub fn cdf<T: Into<f64>>(x: f64, k: T) -> f64 {
+        let k = k.into();
+
+        if x.is_nan() || k.is_nan() || k <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        lower_regularized(0.5 * k, 0.5 * x).unwrap_or(f64::NAN)
+    }
+
+    /// Returns the variance-stabilizing transform `sqrt(2x) - sqrt(2k - 1)`.
+    ///
+    /// For large `k`, the result is approximately standard normal. Returns `NAN` if `x` is negative or `k` is not positive.
+    pub fn sqrt_transform<T: Into<f64>>(x: f64, k: T) -> f64 {
+        let k = k.into();
+
+        if x.is_nan() || k.is_nan() || x < 0.0 || k <= 0.0 {
+            return f64::NAN;
+        }
+
+        // Fisher, R. A. (1922).
+        // On the interpretation of chi-squared from contingency tables, and the calculation of P.
+        sqrt(2.0 * x) - sqrt(2.0 * k - 1.0)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<f64> for ChiSquared {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        crate::sample::chi_squared(self.k, rng)
+    }
+}
+
+#[cfg(feature = "rand-distr-compat")]
+impl core::convert::TryFrom<ChiSquared> for rand_distr::ChiSquared<f64> {
+    type Error = rand_distr::ChiSquaredError;
+
+    fn try_from(chi_squared: ChiSquared) -> Result<Self, Self::Error> {
+        rand_distr::ChiSquared::new(chi_squared.k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChiSquared;
+    use crate::math::sqrt;
+    use crate::Normal;
+
+    const INFINITY: f64 = f64::INFINITY;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0, INFINITY];
+        let expected = [0.0, 0.0, 0.2197, 0.24197, 0.20755, 0.07322, 0.0085, 0.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(ChiSquared::pdf(*input, 3), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_one() {
+        let inputs = [0.0, 0.5, 1.0, 2.0, 5.0, 10.0];
+        let expected = [INFINITY, 0.43939, 0.24197, 0.10378, 0.01464, 0.00085];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(ChiSquared::pdf(*input, 1), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_two() {
+        assert_in_delta(ChiSquared::pdf(0.0, 2), 0.5, 0.00001);
+    }
+
+    #[test]
+    fn test_pdf_nan() {
+        assert!(ChiSquared::pdf(f64::NAN, 3).is_nan());
+        assert!(ChiSquared::pdf(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_non_positive() {
+        assert!(ChiSquared::pdf(1.0, 0).is_nan());
+        assert!(ChiSquared::pdf(1.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0, INFINITY];
+        let expected = [0.0, 0.0, 0.08111, 0.19875, 0.42759, 0.8282, 0.98143, 1.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(ChiSquared::cdf(*input, 3), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_ten() {
+        let inputs = [0.5, 1.0, 2.0, 5.0, 10.0];
+        let expected = [0.00001, 0.00017, 0.00366, 0.10882, 0.55951];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(ChiSquared::cdf(*input, 10), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(ChiSquared::cdf(f64::NAN, 3).is_nan());
+        assert!(ChiSquared::cdf(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_non_positive() {
+        assert!(ChiSquared::cdf(1.0, 0).is_nan());
+    }
+
+    #[test]
+    fn test_sqrt_transform() {
+        assert_in_delta(ChiSquared::sqrt_transform(0.0, 5), -3.0, 0.00001);
+        assert_in_delta(ChiSquared::sqrt_transform(12.5, 13), 0.0, 0.00001);
+    }
+
+    #[test]
+    fn test_sqrt_transform_large_k() {
+        // the quantile of the transformed value is close to the standard normal quantile
+        let k = 500.0;
+        let c = sqrt(2.0 * k - 1.0);
+        for z in [-2.0, -1.0, 0.0, 1.0, 2.0] {
+            let x = (z + c) * (z + c) / 2.0;
+            assert_in_delta(ChiSquared::sqrt_transform(x, k), z, 0.000000001);
+            assert_in_delta(ChiSquared::cdf(x, k), Normal::cdf(z, 0.0, 1.0), 0.005);
+        }
+    }
+
+    #[test]
+    fn test_sqrt_transform_invalid() {
+        assert!(ChiSquared::sqrt_transform(-1.0, 3).is_nan());
+        assert!(ChiSquared::sqrt_transform(1.0, 0).is_nan());
+        assert!(ChiSquared::sqrt_transform(f64::NAN, 3).is_nan());
+        assert!(ChiSquared::sqrt_transform(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(ChiSquared::new(3).is_some());
+        assert!(ChiSquared::new(0.5).is_some());
+        assert!(ChiSquared::new(0).is_none());
+        assert!(ChiSquared::new(f64::NAN).is_none());
+        assert!(ChiSquared::new(INFINITY).is_none());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        // integer and gamma-based paths
+        for k in [1.0, 4.0, 2.5, 30.0] {
+            let dist = ChiSquared::new(k).unwrap();
+            let n = 100000;
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..n {
+                let x = dist.sample(&mut rng);
+                sum += x;
+                sum_sq += x * x;
+            }
+            let mean = sum / n as f64;
+            let variance = sum_sq / n as f64 - mean * mean;
+            assert_in_delta(mean / k, 1.0, 0.02);
+            assert_in_delta(variance / (2.0 * k), 1.0, 0.05);
+        }
+    }
+
+    #[cfg(feature = "rand-distr-compat")]
+    #[test]
+    fn test_rand_distr() {
+        use core::convert::TryFrom;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rand_distr::Distribution;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = rand_distr::ChiSquared::try_from(ChiSquared::new(3).unwrap()).unwrap();
+        let n = 100000;
+        let mean = (0..n).map(|_| dist.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert_in_delta(mean, 3.0, 0.05);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::gamma::ln_gamma_sign;
+use crate::math::{fabs, log};
+
+// allowed deviation of the sum of x from one
+const SUM_TOLERANCE: f64 = 1e-9;
+
+/// The Dirichlet distribution.
+pub struct Dirichlet;
+
+impl Dirichlet {
+    /// Returns the natural logarithm of the probability density function (PDF) of the Dirichlet distribution.
+    ///
+    /// `x` must sum to one and have the same length as `alpha`, which needs at least two concentration parameters. Returns `NAN` otherwise.
+    pub fn ln_pdf(x: &[f64], alpha: &[f64]) -> f64 {
+        if x.len() != alpha.len() || alpha.len() < 2 {
+            return f64::NAN;
+        }
+
+        if x.iter().any(|v| v.is_nan()) || alpha.iter().any(|a| !a.is_finite() || *a <= 0.0) {
+            return f64::NAN;
+        }
+
+        if fabs(x.iter().sum::<f64>() - 1.0) > SUM_TOLERANCE {
+            return f64::NAN;
+        }
+
+        if x.iter().any(|v| *v < 0.0) {
+            return f64::NEG_INFINITY;
+        }
+
+        // log of the multivariate beta function
+        let mut ln_norm = -ln_gamma_sign(alpha.iter().sum()).0;
+        let mut ln_kernel = 0.0;
+        for (v, a) in x.iter().zip(alpha) {
+            ln_norm += ln_gamma_sign(*a).0;
+
+            // avoid 0 * -inf when a component is zero
+            if *a != 1.0 {
+                ln_kernel += (a - 1.0) * log(*v);
+            }
+        }
+
+        ln_kernel - ln_norm
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Dirichlet;
+    use crate::math::log;
+    use crate::Beta;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let act = Dirichlet::ln_pdf(&[0.2, 0.3, 0.5], &[2.0, 3.0, 4.0]);
+        assert_in_delta(act, 2.0228711901914416, 0.000000000001);
+    }
+
+    #[test]
+    fn test_ln_pdf_fractional() {
+        let act = Dirichlet::ln_pdf(&[0.1, 0.2, 0.3, 0.4], &[0.5, 1.5, 2.5, 0.25]);
+        assert_in_delta(act, 0.01211575081196989, 0.000000000001);
+    }
+
+    #[test]
+    fn test_ln_pdf_uniform() {
+        let act = Dirichlet::ln_pdf(&[0.2, 0.3, 0.5], &[1.0, 1.0, 1.0]);
+        assert_in_delta(act, log(2.0), 0.000000000001);
+    }
+
+    #[test]
+    fn test_ln_pdf_large_alpha() {
+        let act = Dirichlet::ln_pdf(&[0.5, 0.3, 0.2], &[500.0, 300.0, 200.0]);
+        assert_in_delta(act, 6.822379383924549, 0.0000000001);
+    }
+
+    #[test]
+    fn test_ln_pdf_beta() {
+        for x in [0.1, 0.25, 0.5, 0.75, 0.9] {
+            assert_in_delta(
+                Dirichlet::ln_pdf(&[x, 1.0 - x], &[2.0, 5.0]),
+                log(Beta::pdf(x, 2.0, 5.0)),
+                0.000000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_ln_pdf_boundary() {
+        assert_in_delta(
+            Dirichlet::ln_pdf(&[0.0, 1.0], &[1.0, 2.0]),
+            log(2.0),
+            0.000000000001,
+        );
+        assert_eq!(
+            Dirichlet::ln_pdf(&[0.0, 1.0], &[2.0, 2.0]),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(Dirichlet::ln_pdf(&[0.0, 1.0], &[0.5, 2.0]), f64::INFINITY);
+        assert_eq!(
+            Dirichlet::ln_pdf(&[-0.5, 1.5], &[2.0, 2.0]),
+            f64::NEG_INFINITY
+        );
+    }
+
+    #[test]
+    fn test_ln_pdf_sum_tolerance() {
+        assert!(Dirichlet::ln_pdf(&[0.1, 0.2, 0.7 + 1e-12], &[2.0, 3.0, 4.0]).is_finite());
+        assert!(Dirichlet::ln_pdf(&[0.1, 0.2, 0.6], &[2.0, 3.0, 4.0]).is_nan());
+        assert!(Dirichlet::ln_pdf(&[0.1, 0.2, 0.8], &[2.0, 3.0, 4.0]).is_nan());
+    }
+
+    #[test]
+    fn test_ln_pdf_length_mismatch() {
+        assert!(Dirichlet::ln_pdf(&[0.5, 0.5], &[2.0, 3.0, 4.0]).is_nan());
+        assert!(Dirichlet::ln_pdf(&[0.2, 0.3, 0.5], &[2.0, 3.0]).is_nan());
+        assert!(Dirichlet::ln_pdf(&[1.0], &[2.0]).is_nan());
+        assert!(Dirichlet::ln_pdf(&[], &[]).is_nan());
+    }
+
+    #[test]
+    fn test_ln_pdf_nan() {
+        assert!(Dirichlet::ln_pdf(&[f64::NAN, 0.5], &[2.0, 3.0]).is_nan());
+        assert!(Dirichlet::ln_pdf(&[0.5, 0.5], &[f64::NAN, 3.0]).is_nan());
+    }
+
+    #[test]
+    fn test_ln_pdf_invalid_alpha() {
+        assert!(Dirichlet::ln_pdf(&[0.5, 0.5], &[0.0, 3.0]).is_nan());
+        assert!(Dirichlet::ln_pdf(&[0.5, 0.5], &[2.0, -1.0]).is_nan());
+        assert!(Dirichlet::ln_pdf(&[0.5, 0.5], &[2.0, f64::INFINITY]).is_nan());
+    }
+}
@@ -0,0 +1,29 @@
+use crate::math::floor;
+use crate::Normal;
+
+// returns the smallest k with cdf(k) >= p, starting from a normal approximation
+// with a skewness correction and stepping until cdf(k - 1) < p <= cdf(k)
+pub(crate) fn seeded_ppf(
+    p: f64,
+    mean: f64,
+    std_dev: f64,
+    skewness: f64,
+    max: f64,
+    cdf: impl Fn(f64) -> f64,
+) -> f64 {
+    let z = Normal::std_ppf(p);
+    // Cornish-Fisher expansion
+    let w = z + (z * z - 1.0) * skewness / 6.0;
+    let mut k = floor(mean + std_dev * w + 0.5).clamp(0.0, max);
+
+    if cdf(k) >= p {
+        while k > 0.0 && cdf(k - 1.0) >= p {
+            k -= 1.0;
+        }
+    } else {
+        while k < max && cdf(k) < p {
+            k += 1.0;
+        }
+    }
+    k
+}
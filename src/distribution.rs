@@ -0,0 +1,292 @@
+use crate::math::log;
+use crate::stats::integrate;
+
+/// A continuous probability distribution with stored parameters.
+pub trait ContinuousDistribution {
+    /// Returns the probability density function (PDF).
+    fn pdf(&self, x: f64) -> f64;
+
+    /// Returns the cumulative distribution function (CDF).
+    fn cdf(&self, x: f64) -> f64;
+
+    /// Returns the percent-point/quantile function (PPF).
+    fn ppf(&self, p: f64) -> f64;
+
+    /// Returns the natural logarithm of the probability density function (PDF).
+    ///
+    /// Defaults to `ln(pdf(x))`, which is `-INFINITY` where the density underflows.
+    fn ln_pdf(&self, x: f64) -> f64 {
+        log(self.pdf(x))
+    }
+
+    /// Returns the quantiles at probabilities 0.025, 0.25, 0.5, 0.75, and 0.975.
+    ///
+    /// These are the whiskers, quartiles, and median of a theoretical box plot.
+    fn five_number_summary(&self) -> [f64; 5] {
+        [
+            self.ppf(0.025),
+            self.ppf(0.25),
+            self.ppf(0.5),
+            self.ppf(0.75),
+            self.ppf(0.975),
+        ]
+    }
+
+    /// Returns the lower and upper bounds of the support.
+    ///
+    /// Bounds may be infinite.
+    fn support(&self) -> (f64, f64) {
+        (self.ppf(0.0), self.ppf(1.0))
+    }
+
+    /// Returns the differential entropy in nats.
+    ///
+    /// Defaults to integrating `-pdf(x) ln pdf(x)` over the support by quadrature, which may be inaccurate for unbounded densities.
+    fn entropy(&self) -> f64 {
+        let (lower, upper) = self.support();
+        if lower.is_nan() || upper.is_nan() || lower >= upper {
+            return f64::NAN;
+        }
+
+        integrate(
+            |x| {
+                let p = self.pdf(x);
+                // the integrand tends to zero as the density does
+                if p > 0.0 && p.is_finite() {
+                    -p * log(p)
+                } else {
+                    0.0
+                }
+            },
+            lower,
+            upper,
+        )
+    }
+}
+
+/// A distribution whose parameters can be estimated by the method of moments.
+pub trait FitMoments: Sized {
+    /// Fits the distribution to data by matching the sample mean and variance.
+    ///
+    /// Returns `None` if `data` is empty or its moments are degenerate or infeasible for the distribution.
+    fn fit_moments(data: &[f64]) -> Option<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContinuousDistribution;
+    use crate::math::log;
+    use crate::{Arcsine, Beta, Cauchy, Exponential, HalfT, LocationScaleT, Normal, StudentsT};
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    // relative to the scale of x, so wide grids like Cauchy's can share a helper
+    fn assert_round_trip<D: ContinuousDistribution>(
+        d: &D,
+        xs: &[f64],
+        ps: &[f64],
+        x_tol: f64,
+        p_tol: f64,
+    ) {
+        for &x in xs {
+            let act = d.ppf(d.cdf(x));
+            assert!(
+                (act - x).abs() <= x_tol * x.abs().max(1.0),
+                "ppf(cdf({})) = {}",
+                x,
+                act
+            );
+        }
+        for &p in ps {
+            let act = d.cdf(d.ppf(p));
+            assert!((act - p).abs() <= p_tol, "cdf(ppf({})) = {}", p, act);
+        }
+    }
+
+    fn tail<D: ContinuousDistribution>(d: &D, x: f64) -> f64 {
+        1.0 - d.cdf(x)
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_in_delta(tail(&Normal::new(0.0, 1.0).unwrap(), 1.0), 0.15866, 0.00001);
+        assert_in_delta(tail(&StudentsT::new(1).unwrap(), 1.0), 0.25, 0.00001);
+    }
+
+    #[test]
+    fn test_five_number_summary() {
+        let t = StudentsT::new(3).unwrap();
+        let expected = [-3.18245, -0.76489, 0.0, 0.76489, 3.18245];
+        for (act, exp) in t.five_number_summary().iter().zip(expected) {
+            assert_in_delta(*act, exp, 0.00001);
+        }
+    }
+
+    // uses the default methods rather than the closed forms
+    struct Quadrature<D>(D);
+
+    impl<D: ContinuousDistribution> ContinuousDistribution for Quadrature<D> {
+        fn pdf(&self, x: f64) -> f64 {
+            self.0.pdf(x)
+        }
+
+        fn cdf(&self, x: f64) -> f64 {
+            self.0.cdf(x)
+        }
+
+        fn ppf(&self, p: f64) -> f64 {
+            self.0.ppf(p)
+        }
+    }
+
+    #[test]
+    fn test_ln_pdf() {
+        let cauchy = Cauchy::new(1.0, 2.0).unwrap();
+        for x in [-5.0, 0.0, 1.0, 20.0] {
+            assert_in_delta(cauchy.ln_pdf(x), log(cauchy.pdf(x)), 0.000000000001);
+        }
+
+        // overrides stay finite where the density underflows
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        assert_eq!(Quadrature(normal).ln_pdf(40.0), f64::NEG_INFINITY);
+        assert_in_delta(normal.ln_pdf(40.0), -800.9189385332047, 0.000000001);
+        let t = StudentsT::new(3).unwrap();
+        assert_eq!(t.ln_pdf(2.0), StudentsT::ln_pdf(2.0, 3));
+    }
+
+    #[test]
+    fn test_support() {
+        assert_eq!(
+            Normal::new(1.0, 2.0).unwrap().support(),
+            (f64::NEG_INFINITY, f64::INFINITY)
+        );
+        assert_eq!(HalfT::new(3, 2.0).unwrap().support(), (0.0, f64::INFINITY));
+        assert_eq!(Arcsine::new(-1.0, 2.0).unwrap().support(), (-1.0, 2.0));
+    }
+
+    #[test]
+    fn test_entropy_normal() {
+        for (mean, std_dev) in [(0.0, 1.0), (1.0, 2.0), (-3.0, 0.1), (10.0, 50.0)] {
+            let normal = Normal::new(mean, std_dev).unwrap();
+            assert_in_delta(
+                Quadrature(normal).entropy(),
+                Normal::entropy(mean, std_dev),
+                0.000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_entropy() {
+        assert_in_delta(
+            StudentsT::new(3).unwrap().entropy(),
+            1.773477571863291,
+            0.000001,
+        );
+        assert_in_delta(
+            Cauchy::new(0.0, 2.0).unwrap().entropy(),
+            3.224171427529236,
+            0.000001,
+        );
+        assert_in_delta(
+            Arcsine::new(0.0, 1.0).unwrap().entropy(),
+            -0.24156447527049044,
+            0.000000000001,
+        );
+    }
+
+    #[test]
+    fn test_five_number_summary_normal() {
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        assert_eq!(
+            normal.five_number_summary(),
+            Normal::five_number_summary(1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_method_syntax() {
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        assert_in_delta(normal.pdf(1.0), 0.19947, 0.00001);
+        assert_in_delta(normal.cdf(1.0), 0.5, 0.00001);
+        assert_in_delta(normal.ppf(0.5), 1.0, 0.00001);
+    }
+
+    const PS: [f64; 11] = [
+        1e-6, 1e-4, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.9999, 0.999999,
+    ];
+
+    #[test]
+    fn test_round_trip_normal() {
+        // within five standard deviations, where the lower tail of cdf keeps its precision
+        let xs = [-9.0, -5.0, -3.0, -1.0, 0.0, 1.0, 2.0, 3.0, 5.0, 7.0, 11.0];
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        assert_round_trip(&normal, &xs, &PS, 1e-8, 1e-12);
+    }
+
+    #[test]
+    fn test_round_trip_students_t() {
+        let xs = [-10.0, -5.0, -2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0];
+        for n in [0.5, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0, 7.3, 10.0, 30.0] {
+            let t = StudentsT::new(n).unwrap();
+            // cdf is rounded near one, which limits how well ppf can recover large x
+            assert_round_trip(&t, &xs, &PS, 1e-8, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_location_scale_t() {
+        let xs = [-40.0, -10.0, -3.0, -1.0, 0.0, 1.0, 2.0, 5.0, 20.0];
+        for n in [0.5, 1.0, 3.0, 10.0] {
+            let t = LocationScaleT::new(1.0, 2.0, n).unwrap();
+            assert_round_trip(&t, &xs, &PS, 1e-9, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_beta() {
+        let xs = [0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99];
+        for (alpha, beta) in [(0.5, 0.5), (1.0, 1.0), (2.0, 5.0), (8.0, 3.0)] {
+            let b = Beta::new(alpha, beta).unwrap();
+            // the density is unbounded near 1 for beta below one, so one ulp of x moves cdf by more
+            assert_round_trip(&b, &xs, &PS, 1e-9, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_exponential() {
+        let xs = [0.01, 0.1, 0.5, 1.0, 2.0, 5.0, 10.0];
+        let exponential = Exponential::new(0.5).unwrap();
+        assert_round_trip(&exponential, &xs, &PS, 1e-9, 1e-12);
+    }
+
+    #[test]
+    fn test_round_trip_half_t() {
+        let xs = [0.01, 0.1, 0.5, 1.0, 2.0, 5.0, 10.0, 20.0];
+        for n in [0.5, 1.0, 2.0, 3.0, 5.0, 10.0] {
+            let half_t = HalfT::new(n, 2.0).unwrap();
+            // folding p around one half loses a few digits near zero
+            assert_round_trip(&half_t, &xs, &PS, 1e-9, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_round_trip_cauchy() {
+        let xs = [-1e6, -1e3, -20.0, -2.0, 0.0, 1.0, 3.0, 20.0, 1e3];
+        let cauchy = Cauchy::new(1.0, 2.0).unwrap();
+        assert_round_trip(&cauchy, &xs, &PS, 1e-9, 1e-12);
+    }
+
+    #[test]
+    fn test_round_trip_arcsine() {
+        let xs = [-1.999, -1.5, -1.0, 0.0, 0.5, 1.0, 2.0, 2.9, 2.999];
+        let arcsine = Arcsine::new(-2.0, 3.0).unwrap();
+        assert_round_trip(&arcsine, &xs, &PS, 1e-9, 1e-10);
+    }
+}
@@ -0,0 +1,491 @@
+#![allow(clippy::excessive_precision)]
+
+use crate::math::{fabs, log, sqrt};
+use core::f64::consts::{LOG2_E, PI};
+
+// Based on FreeBSD's s_erf.c
+// ====================================================
+// Copyright (C) 1993 by Sun Microsystems, Inc. All rights reserved.
+//
+// Developed at SunPro, a Sun Microsystems, Inc. business.
+// Permission to use, copy, modify, and distribute this
+// software is freely granted, provided that this notice
+// is preserved.
+// ====================================================
+
+const ERX: f64 = 8.45062911510467529297e-01;
+const EFX8: f64 = 1.02703333676410069053e+00;
+const PP0: f64 = 1.28379167095512558561e-01;
+const PP1: f64 = -3.25042107247001499370e-01;
+const PP2: f64 = -2.84817495755985104766e-02;
+const PP3: f64 = -5.77027029648944159157e-03;
+const PP4: f64 = -2.37630166566501626084e-05;
+const QQ1: f64 = 3.97917223959155352819e-01;
+const QQ2: f64 = 6.50222499887672944485e-02;
+const QQ3: f64 = 5.08130628187576562776e-03;
+const QQ4: f64 = 1.32494738004321644526e-04;
+const QQ5: f64 = -3.96022827877536812320e-06;
+const PA0: f64 = -2.36211856075265944077e-03;
+const PA1: f64 = 4.14856118683748331666e-01;
+const PA2: f64 = -3.72207876035701323847e-01;
+const PA3: f64 = 3.18346619901161753674e-01;
+const PA4: f64 = -1.10894694282396677476e-01;
+const PA5: f64 = 3.54783043256182359371e-02;
+const PA6: f64 = -2.16637559486879084300e-03;
+const QA1: f64 = 1.06420880400844228286e-01;
+const QA2: f64 = 5.40397917702171048937e-01;
+const QA3: f64 = 7.18286544141962662868e-02;
+const QA4: f64 = 1.26171219808761642112e-01;
+const QA5: f64 = 1.36370839120290507362e-02;
+const QA6: f64 = 1.19844998467991074170e-02;
+const RA0: f64 = -9.86494403484714822705e-03;
+const RA1: f64 = -6.93858572707181764372e-01;
+const RA2: f64 = -1.05586262253232909814e+01;
+const RA3: f64 = -6.23753324503260060396e+01;
+const RA4: f64 = -1.62396669462573470355e+02;
+const RA5: f64 = -1.84605092906711035994e+02;
+const RA6: f64 = -8.12874355063065934246e+01;
+const RA7: f64 = -9.81432934416914548592e+00;
+const SA1: f64 = 1.96512716674392571292e+01;
+const SA2: f64 = 1.37657754143519042600e+02;
+const SA3: f64 = 4.34565877475229228821e+02;
+const SA4: f64 = 6.45387271733267880336e+02;
+const SA5: f64 = 4.29008140027567833386e+02;
+const SA6: f64 = 1.08635005541779435134e+02;
+const SA7: f64 = 6.57024977031928170135e+00;
+const SA8: f64 = -6.04244152148580987438e-02;
+const RB0: f64 = -9.86494292470009928597e-03;
+const RB1: f64 = -7.99283237680523006574e-01;
+const RB2: f64 = -1.77579549177547519889e+01;
+const RB3: f64 = -1.60636384855821916062e+02;
+const RB4: f64 = -6.37566443368389627722e+02;
+const RB5: f64 = -1.02509513161107724954e+03;
+const RB6: f64 = -4.83519191608651397019e+02;
+const SB1: f64 = 3.03380607434824582924e+01;
+const SB2: f64 = 3.25792512996573918826e+02;
+const SB3: f64 = 1.53672958608443695994e+03;
+const SB4: f64 = 3.19985821950859553908e+03;
+const SB5: f64 = 2.55305040643316442583e+03;
+const SB6: f64 = 4.74528541206955367215e+02;
+const SB7: f64 = -2.24409524465858183362e+01;
+
+// tiny value used to round results away from exact 1 and 0
+const TINY: f64 = 2.2250738585072014e-308;
+
+fn high_word(x: f64) -> u32 {
+    (x.to_bits() >> 32) as u32
+}
+
+// Based on FreeBSD's e_exp.c, so erf gives the same results with and without no_std
+// instead of depending on the platform or libm exp
+fn exp(x: f64) -> f64 {
+    const LN2_HI: f64 = 6.93147180369123816490e-01;
+    const LN2_LO: f64 = 1.90821492927058770002e-10;
+    const P1: f64 = 1.66666666666666019037e-01;
+    const P2: f64 = -2.77777777770155933842e-03;
+    const P3: f64 = 6.61375632143793436117e-05;
+    const P4: f64 = -1.65339022054652515390e-06;
+    const P5: f64 = 4.13813679705723846039e-08;
+
+    let hx = high_word(x) & 0x7fffffff;
+    let negative = x < 0.0;
+
+    if hx >= 0x4086232b {
+        // |x| >= 708.39 or NaN
+        if x.is_nan() {
+            return x;
+        }
+        if x > 709.782712893383973096 {
+            return f64::INFINITY;
+        }
+        if x < -745.13321910194110842 {
+            return 0.0;
+        }
+    }
+
+    // reduce x to r = hi - lo in [-0.5 ln 2, 0.5 ln 2] with x = k ln 2 + r
+    let (hi, lo, k) = if hx > 0x3fd62e42 {
+        // |x| > 0.5 ln 2
+        let k = if hx >= 0x3ff0a2b2 {
+            // |x| >= 1.5 ln 2
+            (LOG2_E * x + if negative { -0.5 } else { 0.5 }) as i32
+        } else if negative {
+            -1
+        } else {
+            1
+        };
+        (x - k as f64 * LN2_HI, k as f64 * LN2_LO, k)
+    } else if hx > 0x3e300000 {
+        // |x| > 2^-28
+        (x, 0.0, 0)
+    } else {
+        return 1.0 + x;
+    };
+
+    let r = hi - lo;
+    let rr = r * r;
+    let c = r - rr * (P1 + rr * (P2 + rr * (P3 + rr * (P4 + rr * P5))));
+    let y = 1.0 + (r * c / (2.0 - c) - lo + hi);
+    if k == 0 {
+        y
+    } else {
+        scalbn(y, k)
+    }
+}
+
+// returns x * 2^n, scaling in steps so subnormal results are rounded once
+fn scalbn(x: f64, mut n: i32) -> f64 {
+    let two_1023 = f64::from_bits(0x7fe0000000000000);
+    let two_m969 = f64::from_bits(0x0360000000000000);
+
+    let mut y = x;
+    if n > 1023 {
+        y *= two_1023;
+        n -= 1023;
+        if n > 1023 {
+            y *= two_1023;
+            n -= 1023;
+            if n > 1023 {
+                n = 1023;
+            }
+        }
+    } else if n < -1022 {
+        y *= two_m969;
+        n += 1022 - 53;
+        if n < -1022 {
+            y *= two_m969;
+            n += 1022 - 53;
+            if n < -1022 {
+                n = -1022;
+            }
+        }
+    }
+    y * f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+// erfc for 0.84375 <= |x| < 28
+fn erfc_tail(ix: u32, x: f64) -> f64 {
+    if ix < 0x3ff40000 {
+        // |x| < 1.25
+        let s = fabs(x) - 1.0;
+        let p = PA0 + s * (PA1 + s * (PA2 + s * (PA3 + s * (PA4 + s * (PA5 + s * PA6)))));
+        let q = 1.0 + s * (QA1 + s * (QA2 + s * (QA3 + s * (QA4 + s * (QA5 + s * QA6)))));
+        return 1.0 - ERX - p / q;
+    }
+
+    let x = fabs(x);
+    let s = 1.0 / (x * x);
+    let (r, big_s) = if ix < 0x4006db6d {
+        // |x| < 1 / 0.35
+        (
+            RA0 + s * (RA1 + s * (RA2 + s * (RA3 + s * (RA4 + s * (RA5 + s * (RA6 + s * RA7)))))),
+            1.0 + s
+                * (SA1
+                    + s * (SA2
+                        + s * (SA3 + s * (SA4 + s * (SA5 + s * (SA6 + s * (SA7 + s * SA8))))))),
+        )
+    } else {
+        (
+            RB0 + s * (RB1 + s * (RB2 + s * (RB3 + s * (RB4 + s * (RB5 + s * RB6))))),
+            1.0 + s * (SB1 + s * (SB2 + s * (SB3 + s * (SB4 + s * (SB5 + s * (SB6 + s * SB7)))))),
+        )
+    };
+
+    // split x so exp(-x * x) is computed without cancellation
+    let z = f64::from_bits(x.to_bits() & 0xffffffff00000000);
+    exp(-z * z - 0.5625) * exp((z - x) * (z + x) + r / big_s) / x
+}
+
+// rational approximation of (erf(x) - x) / x for |x| < 0.84375
+fn erf_small(x: f64) -> f64 {
+    let z = x * x;
+    let r = PP0 + z * (PP1 + z * (PP2 + z * (PP3 + z * PP4)));
+    let s = 1.0 + z * (QQ1 + z * (QQ2 + z * (QQ3 + z * (QQ4 + z * QQ5))));
+    r / s
+}
+
+/// Returns the error function.
+pub fn erf(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    let negative = x.is_sign_negative();
+
+    if ix >= 0x7ff00000 {
+        // erf(nan) = nan, erf(+-inf) = +-1
+        return if x.is_nan() {
+            x
+        } else if negative {
+            -1.0
+        } else {
+            1.0
+        };
+    }
+
+    if ix < 0x3feb0000 {
+        // |x| < 0.84375
+        if ix < 0x3e300000 {
+            // |x| < 2^-28
+            return 0.125 * (8.0 * x + EFX8 * x);
+        }
+        return x + x * erf_small(x);
+    }
+
+    let y = if ix < 0x40180000 {
+        // |x| < 6
+        1.0 - erfc_tail(ix, x)
+    } else {
+        1.0 - TINY
+    };
+
+    if negative {
+        -y
+    } else {
+        y
+    }
+}
+
+/// Returns the complementary error function.
+///
+/// This is computed directly rather than as `1.0 - erf(x)`, so it stays accurate for large `x`.
+pub fn erfc(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    let negative = x.is_sign_negative();
+
+    if ix >= 0x7ff00000 {
+        // erfc(nan) = nan, erfc(+inf) = 0, erfc(-inf) = 2
+        return if x.is_nan() {
+            x
+        } else if negative {
+            2.0
+        } else {
+            0.0
+        };
+    }
+
+    if ix < 0x3feb0000 {
+        // |x| < 0.84375
+        if ix < 0x3c700000 {
+            // |x| < 2^-56
+            return 1.0 - x;
+        }
+        let y = erf_small(x);
+        if negative || ix < 0x3fd00000 {
+            // x < 0.25
+            return 1.0 - (x + x * y);
+        }
+        return 0.5 - (x - 0.5 + x * y);
+    }
+
+    if ix < 0x403c0000 {
+        // |x| < 28
+        let y = erfc_tail(ix, x);
+        return if negative { 2.0 - y } else { y };
+    }
+
+    if negative {
+        2.0 - TINY
+    } else {
+        TINY * TINY
+    }
+}
+
+/// Returns the inverse error function.
+pub fn erf_inv(x: f64) -> f64 {
+    if x.is_nan() || !(-1.0..=1.0).contains(&x) {
+        return f64::NAN;
+    }
+
+    if x == 1.0 {
+        return f64::INFINITY;
+    }
+
+    if x == -1.0 {
+        return f64::NEG_INFINITY;
+    }
+
+    // Winitzki, S. (2008).
+    // A handy approximation for the error function and its inverse.
+    let a = 0.147;
+    let ln = log(1.0 - x * x);
+    let t = 2.0 / (PI * a) + ln / 2.0;
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let mut y = sign * sqrt(sqrt(t * t - ln / a) - t);
+
+    // refine with Newton's method
+    // use erfc for the residual in the tails, where 1 - |x| is exact
+    let q = 1.0 - fabs(x);
+    for _ in 0..3 {
+        let r = if q < 0.5 {
+            sign * (q - erfc(fabs(y)))
+        } else {
+            erf(y) - x
+        };
+        y -= r / (2.0 / sqrt(PI) * exp(-y * y));
+    }
+    y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{erf, erf_inv, erfc, exp};
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    fn assert_in_relative_delta(act: f64, exp: f64, delta: f64) {
+        assert!(((exp - act) / exp).abs() < delta, "{} != {}", act, exp);
+    }
+
+    #[test]
+    fn test_erf() {
+        let inputs = [-3.0, -1.0, -0.5, 0.0, 0.1, 0.5, 1.0, 2.0, 3.0];
+        let expected = [
+            -0.9999779095030014,
+            -0.8427007929497149,
+            -0.5204998778130465,
+            0.0,
+            0.1124629160182849,
+            0.5204998778130465,
+            0.8427007929497149,
+            0.9953222650189527,
+            0.9999779095030014,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(erf(*input), exp, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_erf_infinity() {
+        assert_eq!(erf(f64::INFINITY), 1.0);
+        assert_eq!(erf(f64::NEG_INFINITY), -1.0);
+    }
+
+    #[test]
+    fn test_erf_nan() {
+        assert!(erf(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_erfc() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 3.0, 5.0];
+        let expected = [
+            1.8427007929497148,
+            1.0,
+            0.4795001221869535,
+            0.15729920705028513,
+            0.004677734981047266,
+            2.209049699858544e-5,
+            1.537459794428035e-12,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(erfc(*input), exp, 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_erfc_large() {
+        assert_in_relative_delta(erfc(10.0), 2.088487583762545e-45, 1e-13);
+        assert!(erfc(27.0) > 0.0);
+    }
+
+    #[test]
+    fn test_erfc_infinity() {
+        assert_eq!(erfc(f64::INFINITY), 0.0);
+        assert_eq!(erfc(f64::NEG_INFINITY), 2.0);
+    }
+
+    #[test]
+    fn test_erfc_nan() {
+        assert!(erfc(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_erf_inv() {
+        let inputs = [-1.0, -0.999, -0.5, 0.0, 0.1, 0.5, 0.9, 0.999, 1.0];
+        let expected = [
+            f64::NEG_INFINITY,
+            -2.3267537655135247,
+            -0.4769362762044699,
+            0.0,
+            0.08885599049425769,
+            0.4769362762044699,
+            1.163087153676674,
+            2.3267537655135247,
+            f64::INFINITY,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(erf_inv(*input), exp, 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_erf_inv_tail() {
+        assert_in_relative_delta(erf_inv(0.999999999999), 5.042031898572696, 1e-12);
+    }
+
+    #[test]
+    fn test_erf_inv_round_trip() {
+        assert_in_delta(erf(erf_inv(0.5)), 0.5, 1e-15);
+        for x in [-0.99, -0.3, 0.01, 0.7, 0.95] {
+            assert_in_delta(erf(erf_inv(x)), x, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_erf_inv_invalid() {
+        assert!(erf_inv(1.1).is_nan());
+        assert!(erf_inv(-1.1).is_nan());
+        assert!(erf_inv(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_exp() {
+        for i in 0..=20000 {
+            let x = -745.0 + 0.07445 * i as f64;
+            let act = exp(x);
+            let exp = crate::math::exp(x);
+            let ulps = (act.to_bits() as i64 - exp.to_bits() as i64).abs();
+            assert!(ulps <= 1, "{} != {} at {}", act, exp, x);
+        }
+    }
+
+    #[test]
+    fn test_exp_special() {
+        assert_eq!(exp(0.0), 1.0);
+        assert_eq!(exp(1e-300), 1.0);
+        assert_eq!(exp(710.0), f64::INFINITY);
+        assert_eq!(exp(f64::INFINITY), f64::INFINITY);
+        assert_eq!(exp(-746.0), 0.0);
+        assert_eq!(exp(f64::NEG_INFINITY), 0.0);
+        assert!(exp(f64::NAN).is_nan());
+        // subnormal result
+        assert_eq!(exp(-745.0), 4.94e-324);
+    }
+
+    #[test]
+    fn test_bits() {
+        // same bits with and without no_std
+        let inputs = [-6.0, -3.5, -1.1, -0.3, 0.2, 0.9, 1.7, 2.6, 4.2, 27.0];
+        let expected = [
+            (0xbff0000000000000, 0x4000000000000000),
+            (0xbfeffffe710d565e, 0x3fffffff3886ab2f),
+            (0xbfec2aa3d27302c0, 0x3ffe1551e9398160),
+            (0xbfd50838881dea0f, 0x3ff5420e22077a84),
+            (0x3fcc8184b8b25f1c, 0x3fe8df9ed1d36839),
+            (0x3fe98045a6c8a2e6, 0x3fc9fee964dd7469),
+            (0x3fef7b3620b8747b, 0x3f90993be8f17093),
+            (0x3feffe10fffccf10, 0x3f2ef000330f060c),
+            (0x3feffffffe778b3b, 0x3e28874c50bdcef9),
+            (0x3ff0000000000000, 0x0000000000019e0f),
+        ];
+        for (input, (erf_bits, erfc_bits)) in inputs.iter().zip(expected) {
+            assert_eq!(erf(*input).to_bits(), erf_bits);
+            assert_eq!(erfc(*input).to_bits(), erfc_bits);
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use core::fmt;
+
+/// An error from creating a distribution with invalid parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParamError {
+    /// The mean is infinite or `NAN`.
+    NonFiniteMean,
+    /// The standard deviation is infinite or `NAN`.
+    NonFiniteStdDev,
+    /// The standard deviation is zero or negative.
+    NonPositiveStdDev,
+    /// The precision is infinite or `NAN`.
+    NonFinitePrecision,
+    /// The precision is zero or negative.
+    NonPositivePrecision,
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::NonFiniteMean => f.write_str("mean must be finite"),
+            ParamError::NonFiniteStdDev => f.write_str("std_dev must be finite"),
+            ParamError::NonPositiveStdDev => f.write_str("std_dev must be positive"),
+            ParamError::NonFinitePrecision => f.write_str("precision must be finite"),
+            ParamError::NonPositivePrecision => f.write_str("precision must be positive"),
+        }
+    }
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for ParamError {}
+
+// Display is tested through to_string, which needs std
+#[cfg(all(test, not(feature = "no_std")))]
+mod tests {
+    use super::ParamError;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParamError::NonPositiveStdDev.to_string(),
+            "std_dev must be positive"
+        );
+        assert_eq!(ParamError::NonFiniteMean.to_string(), "mean must be finite");
+        assert_eq!(
+            ParamError::NonPositivePrecision.to_string(),
+            "precision must be positive"
+        );
+    }
+}
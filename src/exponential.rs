@@ -0,0 +1,199 @@
+use crate::math::{exp, log1p};
+use crate::stats::{fit_by_moments, Family, Params};
+use crate::{ContinuousDistribution, FitMoments};
+
+/// The exponential distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exponential {
+    rate: f64,
+}
+
+impl Exponential {
+    /// Creates an exponential distribution with the given rate.
+    ///
+    /// Returns `None` if `rate` is not positive and finite.
+    pub fn new(rate: f64) -> Option<Self> {
+        if !rate.is_finite() || rate <= 0.0 {
+            return None;
+        }
+
+        Some(Exponential { rate })
+    }
+
+    /// Returns the probability density function (PDF) of the exponential distribution.
+    pub fn pdf(x: f64, rate: f64) -> f64 {
+        if x.is_nan() || rate.is_nan() || rate <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        rate * exp(-rate * x)
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the exponential distribution.
+    pub fn cdf(x: f64, rate: f64) -> f64 {
+        if x.is_nan() || rate.is_nan() || rate <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        1.0 - exp(-rate * x)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the exponential distribution.
+    pub fn ppf(p: f64, rate: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) || rate.is_nan() || rate <= 0.0 {
+            return f64::NAN;
+        }
+
+        -log1p(-p) / rate
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the exponential distribution.
+    ///
+    /// Alias for [`Exponential::ppf`].
+    pub fn quantile(p: f64, rate: f64) -> f64 {
+        Exponential::ppf(p, rate)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the exponential distribution.
+    ///
+    /// Alias for [`Exponential::ppf`].
+    pub fn inv_cdf(p: f64, rate: f64) -> f64 {
+        Exponential::ppf(p, rate)
+    }
+}
+
+impl ContinuousDistribution for Exponential {
+    fn pdf(&self, x: f64) -> f64 {
+        Exponential::pdf(x, self.rate)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        Exponential::cdf(x, self.rate)
+    }
+
+    fn ppf(&self, p: f64) -> f64 {
+        Exponential::ppf(p, self.rate)
+    }
+}
+
+impl FitMoments for Exponential {
+    // the rate is the reciprocal of the mean, so the variance is not used
+    fn fit_moments(data: &[f64]) -> Option<Self> {
+        match fit_by_moments(Family::Exponential, data) {
+            Params::Exponential { rate } => Exponential::new(rate),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Exponential;
+    use crate::{ContinuousDistribution, FitMoments};
+
+    const INFINITY: f64 = f64::INFINITY;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 3.0, INFINITY];
+        let expected = [
+            0.0,
+            2.0,
+            0.7357588823428847,
+            0.2706705664732254,
+            0.004957504353332717,
+            0.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Exponential::pdf(*input, 2.0), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 3.0, INFINITY];
+        let expected = [
+            0.0,
+            0.0,
+            0.6321205588285577,
+            0.8646647167633873,
+            0.9975212478233336,
+            1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Exponential::cdf(*input, 2.0), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_ppf() {
+        let inputs = [0.0, 0.1, 0.5, 0.9, 1.0];
+        let expected = [
+            0.0,
+            0.05268025782891314,
+            0.34657359027997264,
+            1.151292546497023,
+            INFINITY,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Exponential::ppf(*input, 2.0), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(Exponential::pdf(1.0, 0.0).is_nan());
+        assert!(Exponential::cdf(1.0, -1.0).is_nan());
+        assert!(Exponential::ppf(1.5, 2.0).is_nan());
+        assert!(Exponential::ppf(0.5, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Exponential::new(2.0).is_some());
+        assert!(Exponential::new(0.0).is_none());
+        assert!(Exponential::new(-1.0).is_none());
+        assert!(Exponential::new(f64::NAN).is_none());
+        assert!(Exponential::new(INFINITY).is_none());
+    }
+
+    #[test]
+    fn test_instance() {
+        let exponential = Exponential::new(2.0).unwrap();
+        assert_eq!(exponential.cdf(1.0), Exponential::cdf(1.0, 2.0));
+        assert_eq!(exponential.support(), (0.0, INFINITY));
+    }
+
+    #[test]
+    fn test_fit_moments() {
+        let mut data = [0.0; 1000];
+        for (i, x) in data.iter_mut().enumerate() {
+            let p = (i as f64 + 0.5) / 1000.0;
+            *x = Exponential::ppf(p, 0.5);
+        }
+        let exponential = Exponential::fit_moments(&data).unwrap();
+        assert_in_delta(exponential.rate, 0.5, 0.005);
+    }
+
+    #[test]
+    fn test_fit_moments_degenerate() {
+        assert!(Exponential::fit_moments(&[]).is_none());
+        assert!(Exponential::fit_moments(&[-1.0, 0.5]).is_none());
+    }
+}
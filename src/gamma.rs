@@ -0,0 +1,956 @@
+//! The gamma distribution, the gamma function, and related functions.
+
+use crate::math::{cos, exp, fabs, floor, log, pow, sin, sqrt};
+use crate::stats::{fit_by_moments, Family, Params};
+use crate::FitMoments;
+use core::f64::consts::PI;
+
+// Lanczos approximation with g = 7 and n = 9
+const LANCZOS_G: f64 = 7.0;
+
+#[allow(clippy::excessive_precision)]
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.99999999999980993,
+    676.5203681218851,
+    -1259.1392167224028,
+    771.32342877765313,
+    -176.61502916214059,
+    12.507343278686905,
+    -0.13857109526572012,
+    9.9843695780195716e-6,
+    1.5056327351493116e-7,
+];
+
+/// Options for the iterative evaluation of the regularized incomplete beta and gamma functions.
+///
+/// Use [`Default`] for the options the other functions use.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RegOpts {
+    /// The relative tolerance at which the iteration stops.
+    pub eps: f64,
+    /// The maximum number of iterations.
+    pub max_iter: u32,
+}
+
+impl Default for RegOpts {
+    fn default() -> Self {
+        RegOpts {
+            eps: 1e-15,
+            max_iter: 10000,
+        }
+    }
+}
+
+/// The gamma distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gamma {
+    shape: f64,
+    scale: f64,
+}
+
+impl Gamma {
+    /// Creates a gamma distribution with the given shape and scale.
+    ///
+    /// Returns `None` if `shape` or `scale` is not positive and finite.
+    pub fn new(shape: f64, scale: f64) -> Option<Self> {
+        if !shape.is_finite() || !scale.is_finite() || shape <= 0.0 || scale <= 0.0 {
+            return None;
+        }
+
+        Some(Gamma { shape, scale })
+    }
+
+    /// Returns the probability density function (PDF) of the gamma distribution.
+    pub fn pdf(x: f64, shape: f64, scale: f64) -> f64 {
+        if x.is_nan() || shape.is_nan() || scale.is_nan() || shape <= 0.0 || scale <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x < 0.0 || x == f64::INFINITY {
+            return 0.0;
+        }
+
+        if x == 0.0 {
+            return if shape < 1.0 {
+                f64::INFINITY
+            } else if shape == 1.0 {
+                1.0 / scale
+            } else {
+                0.0
+            };
+        }
+
+        exp((shape - 1.0) * log(x) - x / scale - ln_gamma_sign(shape).0 - shape * log(scale))
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the gamma distribution.
+    pub fn cdf(x: f64, shape: f64, scale: f64) -> f64 {
+        if x.is_nan() || shape.is_nan() || scale.is_nan() || shape <= 0.0 || scale <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        lower_regularized(shape, x / scale).unwrap_or(f64::NAN)
+    }
+}
+
+impl FitMoments for Gamma {
+    fn fit_moments(data: &[f64]) -> Option<Self> {
+        match fit_by_moments(Family::Gamma, data) {
+            Params::Gamma { shape, scale } => Gamma::new(shape, scale),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<f64> for Gamma {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.scale * crate::sample::standard_gamma(self.shape, rng)
+    }
+}
+
+#[cfg(feature = "rand-distr-compat")]
+impl core::convert::TryFrom<Gamma> for rand_distr::Gamma<f64> {
+    type Error = rand_distr::GammaError;
+
+    fn try_from(gamma: Gamma) -> Result<Self, Self::Error> {
+        rand_distr::Gamma::new(gamma.shape, gamma.scale)
+    }
+}
+
+/// Returns the gamma function.
+///
+/// Returns `None` for zero, negative integers, and NaN, where the function is undefined.
+pub fn calculate(x: f64) -> Option<f64> {
+    if is_pole(x) {
+        return None;
+    }
+
+    if x < 0.5 {
+        // reflection formula
+        return Some(PI / (sin(PI * x) * calculate(1.0 - x)?));
+    }
+
+    let (t, a) = lanczos(x);
+    // split power to avoid overflow before multiplying by exp(-t)
+    let p = pow(t, (x - 0.5) / 2.0);
+    Some(sqrt(2.0 * PI) * p * (p * exp(-t)) * a)
+}
+
+/// Returns the natural logarithm of the absolute value of the gamma function.
+///
+/// Unlike [`calculate`], this stays accurate for large `x` where the gamma function overflows. Returns `None` for zero, negative integers, and NaN, where the function is undefined.
+pub fn ln_gamma(x: f64) -> Option<f64> {
+    if is_pole(x) {
+        return None;
+    }
+
+    Some(ln_gamma_sign(x).0)
+}
+
+/// Returns the natural logarithm of the factorial of `n`.
+///
+/// This stays accurate for large `n` where the factorial overflows.
+pub fn ln_factorial(n: u64) -> f64 {
+    // the product is exact or within rounding up to 20!
+    if n <= 20 {
+        return log((1..=n).fold(1.0, |acc, i| acc * i as f64));
+    }
+
+    ln_gamma_sign(n as f64 + 1.0).0
+}
+
+/// Returns the natural logarithm of the absolute value of the gamma function and its sign.
+///
+/// The sign is `1.0` or `-1.0`. At zero and negative integers, returns `(f64::INFINITY, 0.0)`. For NaN, returns `(f64::NAN, f64::NAN)`.
+pub fn ln_gamma_sign(x: f64) -> (f64, f64) {
+    if x.is_nan() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    if is_pole(x) {
+        return (f64::INFINITY, 0.0);
+    }
+
+    if x < 0.5 {
+        // reflection formula
+        let s = sin(PI * x);
+        let sign = if s < 0.0 { -1.0 } else { 1.0 };
+        return (log(PI) - log(fabs(s)) - ln_gamma_sign(1.0 - x).0, sign);
+    }
+
+    if x == f64::INFINITY {
+        return (f64::INFINITY, 1.0);
+    }
+
+    let (t, a) = lanczos(x);
+    (0.5 * log(2.0 * PI) + (x - 0.5) * log(t) - t + log(a), 1.0)
+}
+
+/// Returns the digamma function, the logarithmic derivative of the gamma function.
+///
+/// Returns `None` for zero, negative integers, and NaN, where the function is undefined.
+pub fn digamma(x: f64) -> Option<f64> {
+    if is_pole(x) {
+        return None;
+    }
+
+    if x < 0.0 {
+        // reflection formula
+        return Some(digamma(1.0 - x)? - PI * cos(PI * x) / sin(PI * x));
+    }
+
+    // shift to large x with recurrence formula
+    let mut x = x;
+    let mut result = 0.0;
+    while x < 10.0 {
+        result -= 1.0 / x;
+        x += 1.0;
+    }
+
+    // asymptotic series
+    let z = 1.0 / (x * x);
+    let series = z
+        * (1.0 / 12.0
+            - z * (1.0 / 120.0
+                - z * (1.0 / 252.0
+                    - z * (1.0 / 240.0 - z * (1.0 / 132.0 - z * (691.0 / 32760.0 - z / 12.0))))));
+    Some(result + log(x) - 0.5 / x - series)
+}
+
+/// Returns the trigamma function, the derivative of the digamma function.
+///
+/// Returns `None` for zero, negative integers, and NaN, where the function is undefined.
+pub fn trigamma(x: f64) -> Option<f64> {
+    if is_pole(x) {
+        return None;
+    }
+
+    if x < 0.0 {
+        // reflection formula
+        let s = sin(PI * x);
+        return Some(PI * PI / (s * s) - trigamma(1.0 - x)?);
+    }
+
+    // shift to large x with recurrence formula
+    let mut x = x;
+    let mut result = 0.0;
+    while x < 10.0 {
+        result += 1.0 / (x * x);
+        x += 1.0;
+    }
+
+    // asymptotic series
+    let z = 1.0 / (x * x);
+    let series = z
+        * (1.0 / 6.0
+            - z * (1.0 / 30.0
+                - z * (1.0 / 42.0
+                    - z * (1.0 / 30.0 - z * (5.0 / 66.0 - z * (691.0 / 2730.0 - z * 7.0 / 6.0))))));
+    Some(result + (1.0 + 0.5 / x + series) / x)
+}
+
+/// Returns the regularized lower incomplete gamma function P(s, x).
+///
+/// Returns `None` if `s` is not positive, `x` is negative, or it fails to converge.
+pub fn lower_regularized(s: f64, x: f64) -> Option<f64> {
+    lower_regularized_with(s, x, RegOpts::default())
+}
+
+/// Returns the regularized lower incomplete gamma function P(s, x) with the given options.
+///
+/// Returns `None` if `s` is not positive, `x` is negative, or it fails to converge within `opts.max_iter` iterations.
+pub fn lower_regularized_with(s: f64, x: f64, opts: RegOpts) -> Option<f64> {
+    if s.is_nan() || x.is_nan() || s <= 0.0 || x < 0.0 {
+        return None;
+    }
+
+    if x == 0.0 {
+        return Some(0.0);
+    }
+
+    if x == f64::INFINITY {
+        return Some(1.0);
+    }
+
+    if x < s + 1.0 {
+        incomplete_series(s, x, opts)
+    } else {
+        incomplete_continued_fraction(s, x, opts).map(|q| 1.0 - q)
+    }
+}
+
+/// Returns the regularized upper incomplete gamma function Q(s, x).
+///
+/// Returns `None` if `s` is not positive, `x` is negative, or it fails to converge.
+pub fn upper_regularized(s: f64, x: f64) -> Option<f64> {
+    upper_regularized_with(s, x, RegOpts::default())
+}
+
+/// Returns the regularized upper incomplete gamma function Q(s, x) with the given options.
+///
+/// Returns `None` if `s` is not positive, `x` is negative, or it fails to converge within `opts.max_iter` iterations.
+pub fn upper_regularized_with(s: f64, x: f64, opts: RegOpts) -> Option<f64> {
+    if s.is_nan() || x.is_nan() || s <= 0.0 || x < 0.0 {
+        return None;
+    }
+
+    if x == 0.0 {
+        return Some(1.0);
+    }
+
+    if x == f64::INFINITY {
+        return Some(0.0);
+    }
+
+    if x < s + 1.0 {
+        incomplete_series(s, x, opts).map(|p| 1.0 - p)
+    } else {
+        incomplete_continued_fraction(s, x, opts)
+    }
+}
+
+/// Returns the upper-tail p-value of a chi-squared statistic with `df` degrees of freedom.
+///
+/// This is the regularized upper incomplete gamma function Q(df / 2, stat / 2). Returns `NAN` if `df` is not positive and finite or `stat` is negative.
+pub fn chi_square_p_value(stat: f64, df: f64) -> f64 {
+    if !df.is_finite() || df <= 0.0 {
+        return f64::NAN;
+    }
+
+    upper_regularized(df / 2.0, stat / 2.0).unwrap_or(f64::NAN)
+}
+
+// series expansion of P(s, x), converges rapidly for x < s + 1
+fn incomplete_series(s: f64, x: f64, opts: RegOpts) -> Option<f64> {
+    let mut ap = s;
+    let mut delta = 1.0 / s;
+    let mut sum = delta;
+    for _ in 0..opts.max_iter {
+        ap += 1.0;
+        delta *= x / ap;
+        sum += delta;
+        if fabs(delta) < fabs(sum) * opts.eps {
+            return Some(sum * exp(-x + s * log(x) - ln_gamma_sign(s).0));
+        }
+    }
+
+    None
+}
+
+// continued fraction of Q(s, x) using Lentz's method, converges rapidly for x >= s + 1
+fn incomplete_continued_fraction(s: f64, x: f64, opts: RegOpts) -> Option<f64> {
+    const TINY: f64 = 1e-300;
+
+    let mut b = x + 1.0 - s;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..=opts.max_iter {
+        let i = i as f64;
+        let an = -i * (i - s);
+        b += 2.0;
+        d = an * d + b;
+        if fabs(d) < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if fabs(c) < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if fabs(delta - 1.0) < opts.eps {
+            return Some(exp(-x + s * log(x) - ln_gamma_sign(s).0) * h);
+        }
+    }
+
+    None
+}
+
+fn is_pole(x: f64) -> bool {
+    x.is_nan() || (x <= 0.0 && floor(x) == x)
+}
+
+// returns t and the series sum for x >= 0.5
+fn lanczos(x: f64) -> (f64, f64) {
+    let x = x - 1.0;
+    let mut a = LANCZOS_COEFFICIENTS[0];
+    for (i, c) in LANCZOS_COEFFICIENTS.iter().enumerate().skip(1) {
+        a += c / (x + i as f64);
+    }
+    (x + LANCZOS_G + 0.5, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        calculate, chi_square_p_value, digamma, ln_factorial, ln_gamma, ln_gamma_sign,
+        lower_regularized, lower_regularized_with, trigamma, upper_regularized,
+        upper_regularized_with, Gamma, RegOpts,
+    };
+    use crate::stats::ppf_via_bisection;
+    use crate::FitMoments;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    fn assert_in_relative_delta(act: f64, exp: f64, delta: f64) {
+        assert!(((exp - act) / exp).abs() < delta, "{} != {}", act, exp);
+    }
+
+    #[test]
+    fn test_calculate() {
+        let inputs = [1.0, 2.0, 3.5, 5.0, 10.0];
+        let expected = [1.0, 1.0, 3.32335097044784, 24.0, 362880.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(calculate(*input).unwrap(), exp, 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_calculate_half() {
+        assert_in_delta(calculate(0.5).unwrap(), core::f64::consts::PI.sqrt(), 1e-14);
+    }
+
+    #[test]
+    fn test_calculate_large() {
+        assert_in_relative_delta(calculate(50.0).unwrap(), 6.082818640342675e62, 1e-13);
+        assert_in_relative_delta(calculate(170.5).unwrap(), 5.5620924145560e305, 1e-12);
+        assert_eq!(calculate(200.0).unwrap(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_calculate_tiny() {
+        // behaves like 1 / x - EulerGamma near zero
+        let euler_gamma = 0.5772156649015329;
+        assert_in_relative_delta(calculate(1e-8).unwrap(), 1e8 - euler_gamma, 1e-15);
+        assert_in_relative_delta(calculate(-1e-8).unwrap(), -1e8 - euler_gamma, 1e-15);
+        assert_in_relative_delta(calculate(1e-300).unwrap(), 1e300, 1e-15);
+    }
+
+    #[test]
+    fn test_calculate_negative() {
+        let inputs = [-0.5, -1.5, -2.5];
+        let expected = [-3.544907701811032, 2.363271801207355, -0.9453087204829419];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(calculate(*input).unwrap(), exp, 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_calculate_poles() {
+        assert_eq!(calculate(0.0), None);
+        assert_eq!(calculate(-1.0), None);
+        assert_eq!(calculate(-2.0), None);
+        assert_eq!(calculate(f64::NEG_INFINITY), None);
+    }
+
+    #[test]
+    fn test_calculate_nan() {
+        assert_eq!(calculate(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        let inputs = [0.001, 1.0, 3.5, 20.5, 100.0, 1000.0];
+        let expected = [
+            6.907178885383854,
+            0.0,
+            1.2009736023470743,
+            40.8315009745308,
+            359.1342053695754,
+            5905.220423209181,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(ln_gamma(*input).unwrap(), exp, 1e-11);
+        }
+    }
+
+    #[test]
+    fn test_ln_gamma_large() {
+        assert_in_relative_delta(ln_gamma(1e6).unwrap(), 12815504.569147612, 1e-14);
+        assert_in_relative_delta(ln_gamma(1e10).unwrap(), 220258509288.81058, 1e-14);
+        assert_eq!(ln_gamma(f64::INFINITY), Some(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_gamma_matches_calculate() {
+        for x in [0.1, 0.5, 1.5, 2.5, 7.0, 30.0, 150.0, -0.5, -2.5] {
+            assert_in_delta(
+                ln_gamma(x).unwrap(),
+                calculate(x).unwrap().abs().ln(),
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn test_ln_gamma_poles() {
+        assert_eq!(ln_gamma(0.0), None);
+        assert_eq!(ln_gamma(-1.0), None);
+        assert_eq!(ln_gamma(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_ln_gamma_sign() {
+        let inputs = [0.5, 1.0, 2.0, 10.0, 100.0];
+        let expected = [
+            0.5723649429247001,
+            0.0,
+            0.0,
+            12.80182748008147,
+            359.1342053695754,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            let (value, sign) = ln_gamma_sign(*input);
+            assert_in_delta(value, exp, 1e-12);
+            assert_eq!(sign, 1.0);
+        }
+    }
+
+    #[test]
+    fn test_ln_gamma_sign_negative() {
+        let (value, sign) = ln_gamma_sign(-1.5);
+        assert_in_delta(value, 0.860047015376481, 1e-13);
+        assert_eq!(sign, 1.0);
+
+        let (value, sign) = ln_gamma_sign(-0.5);
+        assert_in_delta(value, 1.2655121234846454, 1e-13);
+        assert_eq!(sign, -1.0);
+
+        let (value, sign) = ln_gamma_sign(-2.5);
+        assert_in_delta(value, -0.05624371649767405, 1e-13);
+        assert_eq!(sign, -1.0);
+    }
+
+    #[test]
+    fn test_ln_gamma_sign_poles() {
+        assert_eq!(ln_gamma_sign(0.0), (f64::INFINITY, 0.0));
+        assert_eq!(ln_gamma_sign(-3.0), (f64::INFINITY, 0.0));
+    }
+
+    #[test]
+    fn test_ln_gamma_sign_nan() {
+        let (value, sign) = ln_gamma_sign(f64::NAN);
+        assert!(value.is_nan());
+        assert!(sign.is_nan());
+    }
+
+    #[test]
+    fn test_digamma() {
+        let inputs = [0.001, 0.5, 1.0, 2.5, 10.0, 100.0, 1e6];
+        let expected = [
+            -1000.5755719318103,
+            -1.9635100260214235,
+            -0.5772156649015329,
+            0.7031566406452432,
+            2.251752589066721,
+            4.600161852738087,
+            13.815510057964191,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(digamma(*input).unwrap(), exp, 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_digamma_negative() {
+        assert_in_delta(digamma(-0.5).unwrap(), 0.03648997397857652, 1e-13);
+        assert_in_delta(digamma(-2.3).unwrap(), 3.3173231575618227, 1e-12);
+    }
+
+    #[test]
+    fn test_digamma_infinity() {
+        assert_eq!(digamma(f64::INFINITY), Some(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_digamma_poles() {
+        assert_eq!(digamma(0.0), None);
+        assert_eq!(digamma(-1.0), None);
+        assert_eq!(digamma(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_trigamma() {
+        let inputs = [0.001, 0.5, 1.0, 2.5, 10.0, 100.0, 1e6];
+        let expected = [
+            1000001.6425331958,
+            4.934802200544679,
+            1.6449340668482264,
+            0.4903577561002349,
+            0.10516633568168575,
+            0.010050166663333571,
+            1.0000005000001666e-6,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(trigamma(*input).unwrap(), exp, 1e-13);
+        }
+    }
+
+    #[test]
+    fn test_trigamma_one() {
+        let pi = core::f64::consts::PI;
+        assert_in_delta(trigamma(1.0).unwrap(), pi * pi / 6.0, 1e-14);
+    }
+
+    #[test]
+    fn test_trigamma_negative() {
+        assert_in_relative_delta(trigamma(-0.5).unwrap(), 8.934802200544679, 1e-13);
+        assert_in_relative_delta(trigamma(-2.3).unwrap(), 14.725912160961291, 1e-12);
+    }
+
+    #[test]
+    fn test_trigamma_infinity() {
+        assert_eq!(trigamma(f64::INFINITY), Some(0.0));
+    }
+
+    #[test]
+    fn test_trigamma_poles() {
+        assert_eq!(trigamma(0.0), None);
+        assert_eq!(trigamma(-1.0), None);
+        assert_eq!(trigamma(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_lower_regularized() {
+        let inputs = [
+            (2.5, 1.0),
+            (2.5, 5.0),
+            (10.0, 3.0),
+            (10.0, 15.0),
+            (100.0, 90.0),
+            (0.5, 0.01),
+            (1000.0, 1100.0),
+        ];
+        let expected = [
+            0.15085496391539036,
+            0.9247647538534878,
+            0.0011024881301154797,
+            0.9301463393005902,
+            0.15822098918643017,
+            0.1124629160182849,
+            0.99894067674607,
+        ];
+        for ((s, x), exp) in inputs.iter().zip(expected) {
+            assert_in_delta(lower_regularized(*s, *x).unwrap(), exp, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_lower_regularized_exponential() {
+        for x in [0.0, 0.1, 0.5, 1.0, 2.0, 5.0, 10.0, 50.0] {
+            assert_in_delta(lower_regularized(1.0, x).unwrap(), 1.0 - (-x).exp(), 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_lower_regularized_infinity() {
+        assert_eq!(lower_regularized(2.0, f64::INFINITY), Some(1.0));
+    }
+
+    #[test]
+    fn test_lower_regularized_invalid() {
+        assert_eq!(lower_regularized(0.0, 1.0), None);
+        assert_eq!(lower_regularized(-1.0, 1.0), None);
+        assert_eq!(lower_regularized(1.0, -1.0), None);
+        assert_eq!(lower_regularized(f64::NAN, 1.0), None);
+        assert_eq!(lower_regularized(1.0, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_upper_regularized() {
+        let inputs = [(2.5, 1.0), (2.5, 5.0), (10.0, 15.0), (1000.0, 1100.0)];
+        let expected = [
+            0.8491450360846096,
+            0.07523524614651218,
+            0.06985366069940977,
+            0.00105932325392998,
+        ];
+        for ((s, x), exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(upper_regularized(*s, *x).unwrap(), exp, 1e-11);
+        }
+    }
+
+    #[test]
+    fn test_upper_regularized_complement() {
+        for (s, x) in [(0.5, 0.2), (3.0, 2.0), (3.0, 4.0), (20.0, 25.0)] {
+            let sum = lower_regularized(s, x).unwrap() + upper_regularized(s, x).unwrap();
+            assert_in_delta(sum, 1.0, 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_upper_regularized_invalid() {
+        assert_eq!(upper_regularized(0.0, 1.0), None);
+        assert_eq!(upper_regularized(1.0, -1.0), None);
+        assert_eq!(upper_regularized(f64::NAN, 1.0), None);
+    }
+
+    #[test]
+    fn test_regularized_with_default() {
+        for (s, x) in [(2.5, 1.0), (2.5, 5.0), (1000.0, 1100.0)] {
+            assert_eq!(
+                lower_regularized_with(s, x, RegOpts::default()),
+                lower_regularized(s, x)
+            );
+            assert_eq!(
+                upper_regularized_with(s, x, RegOpts::default()),
+                upper_regularized(s, x)
+            );
+        }
+    }
+
+    #[test]
+    fn test_regularized_with_eps() {
+        let opts = RegOpts {
+            eps: 1e-6,
+            ..RegOpts::default()
+        };
+        for (s, x) in [(10.0, 5.0), (10.0, 15.0)] {
+            let act = lower_regularized_with(s, x, opts).unwrap();
+            assert_in_delta(act, lower_regularized(s, x).unwrap(), 1e-5);
+            let act = upper_regularized_with(s, x, opts).unwrap();
+            assert_in_delta(act, upper_regularized(s, x).unwrap(), 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_regularized_with_max_iter() {
+        let opts = RegOpts {
+            max_iter: 5,
+            ..RegOpts::default()
+        };
+        // series and continued fraction
+        for (s, x) in [(1000.0, 990.0), (1000.0, 1100.0)] {
+            assert_eq!(lower_regularized_with(s, x, opts), None);
+            assert_eq!(upper_regularized_with(s, x, opts), None);
+        }
+    }
+
+    #[test]
+    fn test_regularized_no_convergence() {
+        assert_eq!(lower_regularized(1e8, 1e8 - 1.0), None);
+        assert_eq!(upper_regularized(1e8, 1e8 - 1.0), None);
+    }
+
+    #[test]
+    fn test_chi_square_p_value() {
+        let inputs = [
+            (3.84, 1.0),
+            (10.0, 5.0),
+            (0.5, 2.0),
+            (100.0, 50.0),
+            (200.0, 10.0),
+        ];
+        let expected = [
+            0.050043521248705103,
+            0.07523524614651218,
+            0.7788007830714049,
+            3.454931382984864e-5,
+            1.6139305336977305e-37,
+        ];
+        for ((stat, df), exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(chi_square_p_value(*stat, *df), exp, 1e-11);
+        }
+    }
+
+    #[test]
+    fn test_chi_square_p_value_bounds() {
+        assert_eq!(chi_square_p_value(0.0, 3.0), 1.0);
+        assert_eq!(chi_square_p_value(f64::INFINITY, 3.0), 0.0);
+    }
+
+    #[test]
+    fn test_chi_square_p_value_chi_squared() {
+        for stat in [0.5, 2.0, 7.5, 20.0] {
+            assert_in_delta(
+                chi_square_p_value(stat, 4.0),
+                1.0 - crate::ChiSquared::cdf(stat, 4),
+                1e-12,
+            );
+        }
+    }
+
+    #[test]
+    fn test_chi_square_p_value_invalid() {
+        assert!(chi_square_p_value(-1.0, 3.0).is_nan());
+        assert!(chi_square_p_value(1.0, 0.0).is_nan());
+        assert!(chi_square_p_value(1.0, -1.0).is_nan());
+        assert!(chi_square_p_value(1.0, f64::INFINITY).is_nan());
+        assert!(chi_square_p_value(f64::NAN, 3.0).is_nan());
+        assert!(chi_square_p_value(1.0, f64::NAN).is_nan());
+    }
+
+    const INFINITY: f64 = f64::INFINITY;
+
+    #[test]
+    fn test_new() {
+        assert!(Gamma::new(2.0, 1.0).is_some());
+        assert!(Gamma::new(0.0, 1.0).is_none());
+        assert!(Gamma::new(2.0, -1.0).is_none());
+        assert!(Gamma::new(f64::NAN, 1.0).is_none());
+        assert!(Gamma::new(2.0, INFINITY).is_none());
+    }
+
+    #[test]
+    fn test_fit_moments() {
+        let mut data = [0.0; 1000];
+        for (i, x) in data.iter_mut().enumerate() {
+            let p = (i as f64 + 0.5) / 1000.0;
+            *x = ppf_via_bisection(|x| Gamma::cdf(x, 3.0, 2.0), p, 0.0, 10.0);
+        }
+        let gamma = Gamma::fit_moments(&data).unwrap();
+        assert_in_delta(gamma.shape, 3.0, 0.05);
+        assert_in_delta(gamma.scale, 2.0, 0.05);
+    }
+
+    #[test]
+    fn test_fit_moments_degenerate() {
+        assert!(Gamma::fit_moments(&[]).is_none());
+        assert!(Gamma::fit_moments(&[2.0, 2.0]).is_none());
+        assert!(Gamma::fit_moments(&[-1.0, 3.0]).is_none());
+    }
+
+    #[test]
+    fn test_pdf() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0, INFINITY];
+        let expected = [
+            0.0, 0.0, 0.03661594, 0.08065691, 0.13836917, 0.12204152, 0.02833456, 0.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Gamma::pdf(*input, 2.5, 2.0), exp, 0.00000001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_small_shape() {
+        let inputs = [0.0, 0.5, 1.0, 2.0, 5.0];
+        let expected = [INFINITY, 0.43939129, 0.24197072, 0.10377687, 0.01464498];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Gamma::pdf(*input, 0.5, 2.0), exp, 0.00000001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_tiny_shape() {
+        assert_in_relative_delta(Gamma::pdf(0.001, 1e-8, 1.0), 9.990004365912545e-6, 1e-12);
+        assert_in_relative_delta(Gamma::pdf(0.5, 1e-4, 2.0), 1.557475536207232e-4, 1e-12);
+    }
+
+    #[test]
+    fn test_pdf_exponential() {
+        assert_in_delta(Gamma::pdf(0.0, 1.0, 2.0), 0.5, 0.00000001);
+    }
+
+    #[test]
+    fn test_pdf_invalid() {
+        assert!(Gamma::pdf(f64::NAN, 2.0, 1.0).is_nan());
+        assert!(Gamma::pdf(1.0, 0.0, 1.0).is_nan());
+        assert!(Gamma::pdf(1.0, 2.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, 10.0, INFINITY];
+        let expected = [
+            0.0, 0.0, 0.00787671, 0.03743423, 0.15085496, 0.58411981, 0.92476475, 1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Gamma::cdf(*input, 2.5, 2.0), exp, 0.00000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_small_shape() {
+        let inputs = [0.5, 1.0, 2.0, 5.0];
+        let expected = [0.52049988, 0.68268949, 0.84270079, 0.97465268];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Gamma::cdf(*input, 0.5, 2.0), exp, 0.00000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_invalid() {
+        assert!(Gamma::cdf(f64::NAN, 2.0, 1.0).is_nan());
+        assert!(Gamma::cdf(1.0, -1.0, 1.0).is_nan());
+        assert!(Gamma::cdf(1.0, 2.0, f64::NAN).is_nan());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        for shape in [0.3, 1.0, 2.5, 10.0] {
+            let dist = Gamma::new(shape, 2.0).unwrap();
+            let n = 200000;
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..n {
+                let x = dist.sample(&mut rng);
+                assert!(x >= 0.0);
+                sum += x;
+                sum_sq += x * x;
+            }
+            let mean = sum / n as f64;
+            let variance = sum_sq / n as f64 - mean * mean;
+            assert_in_delta(mean / (shape * 2.0), 1.0, 0.02);
+            assert_in_delta(variance / (shape * 4.0), 1.0, 0.05);
+        }
+    }
+
+    #[test]
+    fn test_ln_factorial() {
+        assert_eq!(ln_factorial(0), 0.0);
+        assert_eq!(ln_factorial(1), 0.0);
+
+        let inputs = [5, 20, 21, 100, 1000000];
+        let expected = [
+            4.787491742782046,
+            42.335616460753485,
+            45.38013889847691,
+            363.7393755555635,
+            12815518.384658169,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(ln_factorial(*input), exp, 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_ln_factorial_ln_gamma() {
+        for n in [2, 10, 20, 21, 50] {
+            assert_in_relative_delta(ln_factorial(n), ln_gamma(n as f64 + 1.0).unwrap(), 1e-13);
+        }
+    }
+
+    #[cfg(feature = "rand-distr-compat")]
+    #[test]
+    fn test_rand_distr() {
+        use core::convert::TryFrom;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rand_distr::Distribution;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = rand_distr::Gamma::try_from(Gamma::new(2.5, 2.0).unwrap()).unwrap();
+        let n = 100000;
+        let mean = (0..n).map(|_| dist.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert_in_delta(mean, 5.0, 0.05);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{ContinuousDistribution, StudentsT};
+
+/// The half-t distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HalfT {
+    n: f64,
+    scale: f64,
+}
+
+impl HalfT {
+    /// Creates a half-t distribution with the given degrees of freedom and scale.
+    ///
+    /// Returns `None` if `n` is not positive or `scale` is not positive and finite.
+    pub fn new<T: Into<f64>>(n: T, scale: f64) -> Option<Self> {
+        let n = n.into();
+
+        if n.is_nan() || n <= 0.0 || !scale.is_finite() || scale <= 0.0 {
+            return None;
+        }
+
+        Some(HalfT { n, scale })
+    }
+
+    /// Returns the probability density function (PDF) of the half-t distribution.
+    pub fn pdf<T: Into<f64>>(x: f64, n: T, scale: f64) -> f64 {
+        let n = n.into();
+
+        if x.is_nan() || n.is_nan() || scale.is_nan() || n <= 0.0 || scale <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        2.0 * StudentsT::pdf(x / scale, n) / scale
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the half-t distribution.
+    pub fn cdf<T: Into<f64>>(x: f64, n: T, scale: f64) -> f64 {
+        let n = n.into();
+
+        if x.is_nan() || n.is_nan() || scale.is_nan() || n <= 0.0 || scale <= 0.0 {
+            return f64::NAN;
+        }
+
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        2.0 * StudentsT::cdf(x / scale, n) - 1.0
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the half-t distribution.
+    pub fn ppf<T: Into<f64>>(p: f64, n: T, scale: f64) -> f64 {
+        let n = n.into();
+
+        if !(0.0..=1.0).contains(&p) || n.is_nan() || scale.is_nan() || n <= 0.0 || scale <= 0.0 {
+            return f64::NAN;
+        }
+
+        scale * StudentsT::ppf(0.5 + 0.5 * p, n)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the half-t distribution.
+    ///
+    /// Alias for [`HalfT::ppf`].
+    pub fn quantile<T: Into<f64>>(p: f64, n: T, scale: f64) -> f64 {
+        HalfT::ppf(p, n, scale)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the half-t distribution.
+    ///
+    /// Alias for [`HalfT::ppf`].
+    pub fn inv_cdf<T: Into<f64>>(p: f64, n: T, scale: f64) -> f64 {
+        HalfT::ppf(p, n, scale)
+    }
+}
+
+impl ContinuousDistribution for HalfT {
+    fn pdf(&self, x: f64) -> f64 {
+        HalfT::pdf(x, self.n, self.scale)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        HalfT::cdf(x, self.n, self.scale)
+    }
+
+    fn ppf(&self, p: f64) -> f64 {
+        HalfT::ppf(p, self.n, self.scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HalfT;
+
+    const INFINITY: f64 = f64::INFINITY;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, INFINITY];
+        let expected = [0.0, 0.73511, 0.62636, 0.4135, 0.13502, 0.00844, 0.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(HalfT::pdf(*input, 3, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_scale() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, INFINITY];
+        let expected = [0.0, 0.36755, 0.3527, 0.31318, 0.20675, 0.03866, 0.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(HalfT::pdf(*input, 3, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_nan() {
+        assert!(HalfT::pdf(f64::NAN, 3, 1.0).is_nan());
+        assert!(HalfT::pdf(1.0, f64::NAN, 1.0).is_nan());
+        assert!(HalfT::pdf(1.0, 3, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_non_positive() {
+        assert!(HalfT::pdf(1.0, 0, 1.0).is_nan());
+        assert!(HalfT::pdf(1.0, 3, 0.0).is_nan());
+        assert!(HalfT::pdf(1.0, 3, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, INFINITY];
+        let expected = [0.0, 0.0, 0.34855, 0.609, 0.86067, 0.98461, 1.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(HalfT::cdf(*input, 3, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_scale() {
+        let inputs = [-1.0, 0.0, 0.5, 1.0, 2.0, 5.0, INFINITY];
+        let expected = [0.0, 0.0, 0.18127, 0.34855, 0.609, 0.91229, 1.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(HalfT::cdf(*input, 3, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(HalfT::cdf(f64::NAN, 3, 1.0).is_nan());
+        assert!(HalfT::cdf(1.0, f64::NAN, 1.0).is_nan());
+        assert!(HalfT::cdf(1.0, 3, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_non_positive() {
+        assert!(HalfT::cdf(1.0, 0, 1.0).is_nan());
+        assert!(HalfT::cdf(1.0, 3, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf() {
+        let inputs = [0.0, 0.1, 0.5, 0.9, 0.99, 1.0];
+        let expected = [0.0, 0.1366, 0.76489, 2.35336, 5.84091, INFINITY];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(HalfT::ppf(*input, 3, 1.0), exp, 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_ppf_scale() {
+        let inputs = [0.0, 0.1, 0.5, 0.9, 0.99, 1.0];
+        let expected = [0.0, 0.2732, 1.52978, 4.70673, 11.68182, INFINITY];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(HalfT::ppf(*input, 3, 2.0), exp, 0.0002);
+        }
+    }
+
+    #[test]
+    fn test_ppf_nan() {
+        assert!(HalfT::ppf(f64::NAN, 3, 1.0).is_nan());
+        assert!(HalfT::ppf(0.5, f64::NAN, 1.0).is_nan());
+        assert!(HalfT::ppf(0.5, 3, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_invalid() {
+        assert!(HalfT::ppf(-0.1, 3, 1.0).is_nan());
+        assert!(HalfT::ppf(1.1, 3, 1.0).is_nan());
+        assert!(HalfT::ppf(0.5, 0, 1.0).is_nan());
+        assert!(HalfT::ppf(0.5, 3, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_aliases() {
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(HalfT::quantile(p, 3, 2.0), HalfT::ppf(p, 3, 2.0));
+            assert_eq!(HalfT::inv_cdf(p, 3, 2.0), HalfT::ppf(p, 3, 2.0));
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(HalfT::new(3, 2.0).is_some());
+        assert!(HalfT::new(f64::INFINITY, 2.0).is_some());
+        assert!(HalfT::new(0, 2.0).is_none());
+        assert!(HalfT::new(3, 0.0).is_none());
+        assert!(HalfT::new(f64::NAN, 2.0).is_none());
+        assert!(HalfT::new(3, f64::INFINITY).is_none());
+    }
+}
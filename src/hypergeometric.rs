@@ -0,0 +1,141 @@
+use crate::gamma::ln_factorial;
+use crate::math::exp;
+
+// relative tolerance for tables as extreme as the observed one, matching R's fisher.test
+const FISHER_TOLERANCE: f64 = 1e-7;
+
+/// The hypergeometric distribution.
+pub struct Hypergeometric;
+
+impl Hypergeometric {
+    /// Returns the probability mass function (PMF) of the hypergeometric distribution.
+    ///
+    /// This is the probability of `k` successes in `draws` draws without replacement from a population of size `population` containing `successes` successes. Returns `NAN` if `successes` or `draws` is greater than `population`.
+    pub fn pmf(k: u64, population: u64, successes: u64, draws: u64) -> f64 {
+        if successes > population || draws > population {
+            return f64::NAN;
+        }
+
+        if k > successes || k > draws || draws - k > population - successes {
+            return 0.0;
+        }
+
+        exp(Hypergeometric::ln_pmf(k, population, successes, draws))
+    }
+
+    // binomial coefficients in log space to avoid overflow for large populations
+    fn ln_pmf(k: u64, population: u64, successes: u64, draws: u64) -> f64 {
+        ln_choose(successes, k) + ln_choose(population - successes, draws - k)
+            - ln_choose(population, draws)
+    }
+
+    /// Returns the two-sided p-value of Fisher's exact test for the 2x2 contingency table `[[a, b], [c, d]]`.
+    ///
+    /// This sums the probabilities of all tables with the same margins that are no more likely than the observed one, like R's `fisher.test`.
+    pub fn fishers_exact(a: u64, b: u64, c: u64, d: u64) -> f64 {
+        // first row is the draws and first column is the successes
+        let draws = a + b;
+        let successes = a + c;
+        let population = draws + c + d;
+
+        let lower = (draws + successes).saturating_sub(population);
+        let upper = draws.min(successes);
+        let observed = Hypergeometric::ln_pmf(a, population, successes, draws);
+
+        let mut p_value = 0.0;
+        for k in lower..=upper {
+            let ln_p = Hypergeometric::ln_pmf(k, population, successes, draws);
+            // compare in log space so the tolerance is relative
+            if ln_p <= observed + FISHER_TOLERANCE {
+                p_value += exp(ln_p);
+            }
+        }
+        p_value.min(1.0)
+    }
+}
+
+fn ln_choose(n: u64, k: u64) -> f64 {
+    ln_factorial(n) - ln_factorial(k) - ln_factorial(n - k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Hypergeometric;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pmf() {
+        let expected = [
+            0.014285714285714285,
+            0.22857142857142856,
+            0.5142857142857142,
+            0.22857142857142856,
+            0.014285714285714285,
+        ];
+        for (k, exp) in expected.iter().enumerate() {
+            assert_in_delta(Hypergeometric::pmf(k as u64, 8, 4, 4), *exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_pmf_out_of_range() {
+        assert_eq!(Hypergeometric::pmf(5, 8, 4, 4), 0.0);
+        assert_eq!(Hypergeometric::pmf(0, 8, 6, 4), 0.0);
+        assert_eq!(Hypergeometric::pmf(0, 0, 0, 0), 1.0);
+    }
+
+    #[test]
+    fn test_pmf_invalid() {
+        assert!(Hypergeometric::pmf(1, 8, 9, 4).is_nan());
+        assert!(Hypergeometric::pmf(1, 8, 4, 9).is_nan());
+    }
+
+    #[test]
+    fn test_fishers_exact() {
+        // lady tasting tea
+        assert_in_delta(
+            Hypergeometric::fishers_exact(3, 1, 1, 3),
+            0.4857142857142857,
+            0.000000000001,
+        );
+        assert_in_delta(
+            Hypergeometric::fishers_exact(1, 9, 11, 3),
+            0.0027594561852200836,
+            0.000000000001,
+        );
+        assert_in_delta(
+            Hypergeometric::fishers_exact(10, 2, 3, 15),
+            0.0005367241191434358,
+            0.000000000001,
+        );
+        assert_in_delta(
+            Hypergeometric::fishers_exact(0, 5, 5, 0),
+            0.007936507936507936,
+            0.000000000001,
+        );
+    }
+
+    #[test]
+    fn test_fishers_exact_large() {
+        let act = Hypergeometric::fishers_exact(100, 50, 60, 120);
+        assert!(
+            (act / 1.7421143704782435e-09 - 1.0).abs() < 0.000001,
+            "{}",
+            act
+        );
+    }
+
+    #[test]
+    fn test_fishers_exact_degenerate() {
+        assert_eq!(Hypergeometric::fishers_exact(0, 0, 0, 0), 1.0);
+        assert_eq!(Hypergeometric::fishers_exact(7, 0, 0, 0), 1.0);
+        assert_eq!(Hypergeometric::fishers_exact(3, 4, 0, 0), 1.0);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::math::{floor, pow};
+
+/// The Irwin-Hall distribution, the sum of `n` independent standard uniform random variables.
+///
+/// The exact formulas are alternating sums, so for large `n`, where the distribution approaches a normal distribution with mean `n / 2` and variance `n / 12`, the normal approximation is more accurate.
+pub struct IrwinHall;
+
+impl IrwinHall {
+    /// Returns the probability density function (PDF) of the Irwin-Hall distribution.
+    pub fn pdf(x: f64, n: u32) -> f64 {
+        if x.is_nan() || n == 0 {
+            return f64::NAN;
+        }
+
+        let nf = n as f64;
+        if !(0.0..=nf).contains(&x) {
+            return 0.0;
+        }
+
+        // distribution is symmetric, and the sum has fewer terms below the center
+        let x = if x > nf / 2.0 { nf - x } else { x };
+        alternating_sum(x, n, n - 1) / factorial(n - 1)
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the Irwin-Hall distribution.
+    pub fn cdf(x: f64, n: u32) -> f64 {
+        if x.is_nan() || n == 0 {
+            return f64::NAN;
+        }
+
+        let nf = n as f64;
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        if x >= nf {
+            return 1.0;
+        }
+
+        if x > nf / 2.0 {
+            1.0 - alternating_sum(nf - x, n, n) / factorial(n)
+        } else {
+            alternating_sum(x, n, n) / factorial(n)
+        }
+    }
+}
+
+// returns the sum of (-1)^k * C(n, k) * (x - k)^m for k from 0 to floor(x)
+fn alternating_sum(x: f64, n: u32, m: u32) -> f64 {
+    let mut sum = 0.0;
+    let mut c = 1.0;
+    let mut sign = 1.0;
+    let mut k = 0;
+    while k <= n && (k as f64) <= floor(x) {
+        sum += sign * c * pow(x - k as f64, m as f64);
+        c = c * (n - k) as f64 / (k + 1) as f64;
+        sign = -sign;
+        k += 1;
+    }
+    sum
+}
+
+fn factorial(n: u32) -> f64 {
+    (1..=n).fold(1.0, |acc, i| acc * i as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IrwinHall;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pdf_one() {
+        let inputs = [-0.5, 0.0, 0.25, 0.5, 1.0, 1.5];
+        let expected = [0.0, 1.0, 1.0, 1.0, 1.0, 0.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(IrwinHall::pdf(*input, 1), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_two() {
+        for i in 0..=40 {
+            let x = -0.5 + 0.075 * i as f64;
+            let exp = if !(0.0..=2.0).contains(&x) {
+                0.0
+            } else if x <= 1.0 {
+                x
+            } else {
+                2.0 - x
+            };
+            assert_in_delta(IrwinHall::pdf(x, 2), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_three() {
+        for i in 0..=40 {
+            let x = -0.5 + 0.1 * i as f64;
+            let exp = if !(0.0..=3.0).contains(&x) {
+                0.0
+            } else if x <= 1.0 {
+                x * x / 2.0
+            } else if x <= 2.0 {
+                (-2.0 * x * x + 6.0 * x - 3.0) / 2.0
+            } else {
+                (3.0 - x) * (3.0 - x) / 2.0
+            };
+            assert_in_delta(IrwinHall::pdf(x, 3), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_pdf_nan() {
+        assert!(IrwinHall::pdf(f64::NAN, 3).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_zero_n() {
+        assert!(IrwinHall::pdf(0.5, 0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_two() {
+        for i in 0..=40 {
+            let x = -0.5 + 0.075 * i as f64;
+            let exp = if x < 0.0 {
+                0.0
+            } else if x <= 1.0 {
+                x * x / 2.0
+            } else if x <= 2.0 {
+                1.0 - (2.0 - x) * (2.0 - x) / 2.0
+            } else {
+                1.0
+            };
+            assert_in_delta(IrwinHall::cdf(x, 2), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_three() {
+        for i in 0..=40 {
+            let x = -0.5 + 0.1 * i as f64;
+            let exp = if x < 0.0 {
+                0.0
+            } else if x <= 1.0 {
+                x * x * x / 6.0
+            } else if x <= 2.0 {
+                (-2.0 * x * x * x + 9.0 * x * x - 9.0 * x + 3.0) / 6.0
+            } else if x <= 3.0 {
+                1.0 - (3.0 - x) * (3.0 - x) * (3.0 - x) / 6.0
+            } else {
+                1.0
+            };
+            assert_in_delta(IrwinHall::cdf(x, 3), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_symmetric() {
+        for n in [4, 7, 12] {
+            assert_in_delta(IrwinHall::cdf(n as f64 / 2.0, n), 0.5, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(IrwinHall::cdf(f64::NAN, 3).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_zero_n() {
+        assert!(IrwinHall::cdf(0.5, 0).is_nan());
+    }
+}
@@ -1,16 +1,58 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(feature = "no_std", no_std)]
-#![cfg_attr(feature = "no_std", forbid(unsafe_code))]
-#![cfg_attr(not(feature = "no_std"), deny(unsafe_code))]
+#![forbid(unsafe_code)]
 
+mod arcsine;
+mod bates;
+pub mod beta;
+mod binomial;
+mod cauchy;
+mod chi_squared;
+mod dirichlet;
+mod discrete;
+mod distribution;
+mod erf;
+mod error;
+mod exponential;
+pub mod gamma;
+mod half_t;
+mod hypergeometric;
+mod irwin_hall;
+mod link;
+mod location_scale_t;
+mod multinomial;
 mod normal;
+mod poisson;
+#[cfg(feature = "rand")]
+mod sample;
+pub mod stats;
 mod students_t;
 
-#[cfg(feature = "no_std")]
+// the libm feature is implied by no_std and can also be used with std
+#[cfg(feature = "libm")]
 use libm as math;
 
-#[cfg(not(feature = "no_std"))]
+#[cfg(not(feature = "libm"))]
 mod math;
 
+pub use arcsine::Arcsine;
+pub use bates::Bates;
+pub use beta::Beta;
+pub use binomial::Binomial;
+pub use cauchy::Cauchy;
+pub use chi_squared::ChiSquared;
+pub use dirichlet::Dirichlet;
+pub use distribution::{ContinuousDistribution, FitMoments};
+pub use erf::{erf, erf_inv, erfc};
+pub use error::ParamError;
+pub use exponential::Exponential;
+pub use gamma::Gamma;
+pub use half_t::HalfT;
+pub use hypergeometric::Hypergeometric;
+pub use irwin_hall::IrwinHall;
+pub use link::{logit, probit, sigmoid};
+pub use location_scale_t::LocationScaleT;
+pub use multinomial::Multinomial;
 pub use normal::Normal;
-pub use students_t::StudentsT;
+pub use poisson::Poisson;
+pub use students_t::{CdfBranch, StudentsT};
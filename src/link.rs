@@ -0,0 +1,148 @@
+use crate::math::{exp, log};
+use crate::Normal;
+
+/// Returns the probit function, the standard normal PPF of `p`.
+///
+/// The inverse is [`Normal::std_cdf`]. Returns `NAN` if `p` is outside `(0, 1)`.
+pub fn probit(p: f64) -> f64 {
+    if p.is_nan() || p <= 0.0 || p >= 1.0 {
+        return f64::NAN;
+    }
+
+    Normal::std_ppf(p)
+}
+
+/// Returns the logit function, the log-odds `ln(p / (1 - p))`.
+///
+/// The inverse is [`sigmoid`]. Returns `NAN` if `p` is outside `(0, 1)`.
+pub fn logit(p: f64) -> f64 {
+    if p.is_nan() || p <= 0.0 || p >= 1.0 {
+        return f64::NAN;
+    }
+
+    // 1 - p is exact for p >= 0.5
+    log(p / (1.0 - p))
+}
+
+/// Returns the logistic sigmoid function `1 / (1 + exp(-x))`.
+///
+/// This is the CDF of the standard logistic distribution.
+pub fn sigmoid(x: f64) -> f64 {
+    // only exponentiate non-positive values so exp cannot overflow
+    if x >= 0.0 {
+        1.0 / (1.0 + exp(-x))
+    } else {
+        let e = exp(x);
+        e / (1.0 + e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{logit, probit, sigmoid};
+    use crate::Normal;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    fn assert_in_relative_delta(act: f64, exp: f64, delta: f64) {
+        assert!(((exp - act) / exp).abs() < delta, "{} != {}", act, exp);
+    }
+
+    #[test]
+    fn test_probit() {
+        let inputs = [1e-10, 0.025, 0.25, 0.5, 0.75, 0.975];
+        let expected = [
+            -6.361340902404057,
+            -1.9599639845400543,
+            -0.6744897501960817,
+            0.0,
+            0.6744897501960817,
+            1.9599639845400543,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(probit(*input), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_probit_inverse() {
+        for p in [0.001, 0.1, 0.3, 0.5, 0.7, 0.9, 0.999] {
+            assert_in_delta(Normal::std_cdf(probit(p)), p, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_probit_invalid() {
+        for p in [0.0, 1.0, -0.5, 1.5, f64::NAN] {
+            assert!(probit(p).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_logit() {
+        let inputs = [1e-10, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0 - 1e-10];
+        let expected = [
+            -23.025850929840455,
+            -2.1972245773362196,
+            -1.0986122886681098,
+            0.0,
+            1.0986122886681098,
+            2.1972245773362196,
+            23.025850929840455,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(logit(*input), exp, 0.000001);
+        }
+    }
+
+    #[test]
+    fn test_logit_invalid() {
+        for p in [0.0, 1.0, -0.5, 1.5, f64::NAN] {
+            assert!(logit(p).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_sigmoid() {
+        let inputs = [-700.0, -30.0, -2.0, 0.0, 0.5, 2.0, 30.0];
+        let expected = [
+            9.85967654375977e-305,
+            9.357622968839299e-14,
+            0.11920292202211756,
+            0.5,
+            0.6224593312018546,
+            0.8807970779778824,
+            0.9999999999999064,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(sigmoid(*input), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_sigmoid_extreme() {
+        // exp(-800) is below the smallest subnormal, so this underflows to zero rather than NAN
+        assert_eq!(sigmoid(-800.0), 0.0);
+        assert_eq!(sigmoid(800.0), 1.0);
+        assert_eq!(sigmoid(f64::NEG_INFINITY), 0.0);
+        assert_eq!(sigmoid(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_sigmoid_nan() {
+        assert!(sigmoid(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_sigmoid_logit() {
+        for p in [1e-10, 0.1, 0.25, 0.5, 0.75, 0.9] {
+            assert_in_relative_delta(sigmoid(logit(p)), p, 0.000000000001);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{ContinuousDistribution, StudentsT};
+
+/// The location-scale Student's t distribution.
+///
+/// This is the Student's t distribution shifted by `location` and scaled by `scale`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LocationScaleT {
+    location: f64,
+    scale: f64,
+    n: f64,
+}
+
+impl LocationScaleT {
+    /// Creates a location-scale Student's t distribution with the given location, scale, and degrees of freedom.
+    ///
+    /// Returns `None` if `location` is not finite, `scale` is not positive and finite, or `n` is not positive.
+    pub fn new<T: Into<f64>>(location: f64, scale: f64, n: T) -> Option<Self> {
+        let n = n.into();
+
+        if !location.is_finite() || !scale.is_finite() || scale <= 0.0 || n.is_nan() || n <= 0.0 {
+            return None;
+        }
+
+        Some(LocationScaleT { location, scale, n })
+    }
+
+    /// Returns the probability density function (PDF) of the location-scale Student's t distribution.
+    pub fn pdf<T: Into<f64>>(x: f64, location: f64, scale: f64, n: T) -> f64 {
+        let n = n.into();
+
+        if x.is_nan()
+            || location.is_nan()
+            || scale.is_nan()
+            || n.is_nan()
+            || scale <= 0.0
+            || n <= 0.0
+        {
+            return f64::NAN;
+        }
+
+        StudentsT::pdf((x - location) / scale, n) / scale
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the location-scale Student's t distribution.
+    pub fn cdf<T: Into<f64>>(x: f64, location: f64, scale: f64, n: T) -> f64 {
+        let n = n.into();
+
+        if x.is_nan()
+            || location.is_nan()
+            || scale.is_nan()
+            || n.is_nan()
+            || scale <= 0.0
+            || n <= 0.0
+        {
+            return f64::NAN;
+        }
+
+        StudentsT::cdf((x - location) / scale, n)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the location-scale Student's t distribution.
+    pub fn ppf<T: Into<f64>>(p: f64, location: f64, scale: f64, n: T) -> f64 {
+        let n = n.into();
+
+        if !(0.0..=1.0).contains(&p)
+            || location.is_nan()
+            || scale.is_nan()
+            || n.is_nan()
+            || scale <= 0.0
+            || n <= 0.0
+        {
+            return f64::NAN;
+        }
+
+        location + scale * StudentsT::ppf(p, n)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the location-scale Student's t distribution.
+    ///
+    /// Alias for [`LocationScaleT::ppf`].
+    pub fn quantile<T: Into<f64>>(p: f64, location: f64, scale: f64, n: T) -> f64 {
+        LocationScaleT::ppf(p, location, scale, n)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the location-scale Student's t distribution.
+    ///
+    /// Alias for [`LocationScaleT::ppf`].
+    pub fn inv_cdf<T: Into<f64>>(p: f64, location: f64, scale: f64, n: T) -> f64 {
+        LocationScaleT::ppf(p, location, scale, n)
+    }
+}
+
+impl ContinuousDistribution for LocationScaleT {
+    fn pdf(&self, x: f64) -> f64 {
+        LocationScaleT::pdf(x, self.location, self.scale, self.n)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        LocationScaleT::cdf(x, self.location, self.scale, self.n)
+    }
+
+    fn ppf(&self, p: f64) -> f64 {
+        LocationScaleT::ppf(p, self.location, self.scale, self.n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LocationScaleT;
+    use crate::StudentsT;
+
+    const INFINITY: f64 = f64::INFINITY;
+    const NEG_INFINITY: f64 = f64::NEG_INFINITY;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pdf() {
+        let inputs = [NEG_INFINITY, -5.0, -1.0, 0.0, 1.0, 2.0, 4.0, 10.0, INFINITY];
+        let expected = [
+            0.0,
+            0.011486018654620668,
+            0.10337416789158601,
+            0.1565904555044143,
+            0.18377629847393068,
+            0.1565904555044143,
+            0.06000858725679369,
+            0.00305975106720384,
+            0.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(
+                LocationScaleT::pdf(*input, 1.0, 2.0, 3),
+                exp,
+                0.000000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_pdf_students_t() {
+        for x in [-10.0, -2.5, -0.5, 0.0, 0.7, 3.0] {
+            assert_in_delta(
+                LocationScaleT::pdf(x, -1.0, 0.5, 4.5),
+                StudentsT::pdf((x + 1.0) / 0.5, 4.5) / 0.5,
+                0.000000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_pdf_nan() {
+        assert!(LocationScaleT::pdf(f64::NAN, 1.0, 2.0, 3).is_nan());
+        assert!(LocationScaleT::pdf(0.0, f64::NAN, 2.0, 3).is_nan());
+        assert!(LocationScaleT::pdf(0.0, 1.0, f64::NAN, 3).is_nan());
+        assert!(LocationScaleT::pdf(0.0, 1.0, 2.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_non_positive() {
+        assert!(LocationScaleT::pdf(0.0, 1.0, 0.0, 3).is_nan());
+        assert!(LocationScaleT::pdf(0.0, 1.0, -2.0, 3).is_nan());
+        assert!(LocationScaleT::pdf(0.0, 1.0, 2.0, 0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [NEG_INFINITY, -5.0, -1.0, 0.0, 1.0, 2.0, 4.0, 10.0, INFINITY];
+        let expected = [
+            0.0,
+            0.028834442811218654,
+            0.19550110947788532,
+            0.3257239824240755,
+            0.5,
+            0.6742760175759245,
+            0.8847080673775885,
+            0.9897547938277733,
+            1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(
+                LocationScaleT::cdf(*input, 1.0, 2.0, 3),
+                exp,
+                0.000000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_cdf_students_t() {
+        for x in [-10.0, -2.5, -0.5, 0.0, 0.7, 3.0] {
+            assert_eq!(
+                LocationScaleT::cdf(x, -1.0, 0.5, 4.5),
+                StudentsT::cdf((x + 1.0) / 0.5, 4.5)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(LocationScaleT::cdf(f64::NAN, 1.0, 2.0, 3).is_nan());
+        assert!(LocationScaleT::cdf(0.0, 1.0, 0.0, 3).is_nan());
+        assert!(LocationScaleT::cdf(0.0, 1.0, 2.0, -1).is_nan());
+    }
+
+    #[test]
+    fn test_ppf() {
+        let inputs = [0.0, 0.01, 0.1, 0.5, 0.9, 0.99, 1.0];
+        let expected = [
+            NEG_INFINITY,
+            -8.081405717136267,
+            -2.27548870739242,
+            1.0,
+            4.275488707392421,
+            10.081405717136264,
+            INFINITY,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(LocationScaleT::ppf(*input, 1.0, 2.0, 3), exp, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_ppf_students_t() {
+        for p in [0.001, 0.1, 0.3, 0.5, 0.7, 0.9, 0.999] {
+            assert_in_delta(
+                LocationScaleT::ppf(p, -1.0, 0.5, 4.5),
+                -1.0 + 0.5 * StudentsT::ppf(p, 4.5),
+                0.000000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_ppf_invalid() {
+        assert!(LocationScaleT::ppf(-0.1, 1.0, 2.0, 3).is_nan());
+        assert!(LocationScaleT::ppf(1.1, 1.0, 2.0, 3).is_nan());
+        assert!(LocationScaleT::ppf(f64::NAN, 1.0, 2.0, 3).is_nan());
+        assert!(LocationScaleT::ppf(0.5, f64::NAN, 2.0, 3).is_nan());
+        assert!(LocationScaleT::ppf(0.5, 1.0, 0.0, 3).is_nan());
+        assert!(LocationScaleT::ppf(0.5, 1.0, 2.0, 0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_aliases() {
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(
+                LocationScaleT::quantile(p, 1.0, 2.0, 3),
+                LocationScaleT::ppf(p, 1.0, 2.0, 3)
+            );
+            assert_eq!(
+                LocationScaleT::inv_cdf(p, 1.0, 2.0, 3),
+                LocationScaleT::ppf(p, 1.0, 2.0, 3)
+            );
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(LocationScaleT::new(1.0, 2.0, 3).is_some());
+        assert!(LocationScaleT::new(1.0, 2.0, f64::INFINITY).is_some());
+        assert!(LocationScaleT::new(f64::INFINITY, 2.0, 3).is_none());
+        assert!(LocationScaleT::new(1.0, 0.0, 3).is_none());
+        assert!(LocationScaleT::new(1.0, f64::INFINITY, 3).is_none());
+        assert!(LocationScaleT::new(1.0, 2.0, 0).is_none());
+        assert!(LocationScaleT::new(1.0, 2.0, f64::NAN).is_none());
+    }
+}
@@ -1,10 +1,11 @@
-#![allow(unsafe_code)]
+#[inline]
+pub fn acos(x: f64) -> f64 {
+    x.acos()
+}
 
-mod c {
-    extern "C" {
-        pub fn erf(x: f64) -> f64;
-        pub fn tgamma(x: f64) -> f64;
-    }
+#[inline]
+pub fn asin(x: f64) -> f64 {
+    x.asin()
 }
 
 #[inline]
@@ -17,11 +18,6 @@ pub fn cos(x: f64) -> f64 {
     x.cos()
 }
 
-#[inline]
-pub fn erf(x: f64) -> f64 {
-    unsafe { c::erf(x) }
-}
-
 #[inline]
 pub fn exp(x: f64) -> f64 {
     x.exp()
@@ -42,6 +38,11 @@ pub fn log(x: f64) -> f64 {
     x.ln()
 }
 
+#[inline]
+pub fn log1p(x: f64) -> f64 {
+    x.ln_1p()
+}
+
 #[inline]
 pub fn pow(x: f64, y: f64) -> f64 {
     x.powf(y)
@@ -58,6 +59,6 @@ pub fn sqrt(x: f64) -> f64 {
 }
 
 #[inline]
-pub fn tgamma(x: f64) -> f64 {
-    unsafe { c::tgamma(x) }
+pub fn tan(x: f64) -> f64 {
+    x.tan()
 }
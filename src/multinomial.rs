@@ -0,0 +1,128 @@
+use crate::gamma::ln_factorial;
+use crate::math::{fabs, log};
+
+// allowed deviation of the sum of probs from one
+const SUM_TOLERANCE: f64 = 1e-9;
+
+/// The multinomial distribution.
+pub struct Multinomial;
+
+impl Multinomial {
+    /// Returns the natural logarithm of the probability mass function (PMF) of the multinomial distribution.
+    ///
+    /// `probs` must sum to one and have the same length as `counts`. Returns `NAN` otherwise, or if the total count overflows `u64`.
+    pub fn ln_pmf(counts: &[u64], probs: &[f64]) -> f64 {
+        if counts.len() != probs.len() || probs.is_empty() {
+            return f64::NAN;
+        }
+
+        if probs.iter().any(|p| !(0.0..=1.0).contains(p)) {
+            return f64::NAN;
+        }
+
+        if fabs(probs.iter().sum::<f64>() - 1.0) > SUM_TOLERANCE {
+            return f64::NAN;
+        }
+
+        let total = match counts.iter().try_fold(0u64, |a, &c| a.checked_add(c)) {
+            Some(total) => total,
+            None => return f64::NAN,
+        };
+
+        // multinomial coefficient in log space to avoid overflow for large counts
+        let mut ln_pmf = ln_factorial(total);
+        for (k, p) in counts.iter().zip(probs) {
+            // avoid 0 * -inf for empty categories
+            if *k == 0 {
+                continue;
+            }
+
+            if *p == 0.0 {
+                return f64::NEG_INFINITY;
+            }
+
+            ln_pmf += *k as f64 * log(*p) - ln_factorial(*k);
+        }
+        ln_pmf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Multinomial;
+    use crate::math::log;
+    use crate::Binomial;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_ln_pmf() {
+        let act = Multinomial::ln_pmf(&[2, 3, 5], &[0.2, 0.3, 0.5]);
+        assert_in_delta(act, -2.4645159601402663, 0.000000000001);
+    }
+
+    #[test]
+    fn test_ln_pmf_empty_category() {
+        let act = Multinomial::ln_pmf(&[0, 4, 1], &[0.25, 0.5, 0.25]);
+        assert_in_delta(act, -2.5494451709255715, 0.000000000001);
+    }
+
+    #[test]
+    fn test_ln_pmf_large_counts() {
+        let act = Multinomial::ln_pmf(&[200000, 300000, 500000], &[0.2, 0.3, 0.5]);
+        assert_in_delta(act, -13.900109453491407, 0.000001);
+    }
+
+    #[test]
+    fn test_ln_pmf_binomial() {
+        for k in [0, 1, 4, 9, 10] {
+            assert_in_delta(
+                Multinomial::ln_pmf(&[k, 10 - k], &[0.3, 0.7]),
+                log(Binomial::pmf(k as f64, 10, 0.3)),
+                0.000000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_ln_pmf_zero_prob() {
+        assert_eq!(Multinomial::ln_pmf(&[1, 2], &[0.0, 1.0]), f64::NEG_INFINITY);
+        assert_eq!(Multinomial::ln_pmf(&[0, 2], &[0.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pmf_no_trials() {
+        assert_eq!(Multinomial::ln_pmf(&[0, 0, 0], &[0.2, 0.3, 0.5]), 0.0);
+    }
+
+    #[test]
+    fn test_ln_pmf_sum_tolerance() {
+        assert!(Multinomial::ln_pmf(&[2, 3, 5], &[0.2, 0.3, 0.5 + 1e-12]).is_finite());
+        assert!(Multinomial::ln_pmf(&[2, 3, 5], &[0.2, 0.3, 0.4]).is_nan());
+        assert!(Multinomial::ln_pmf(&[2, 3, 5], &[0.2, 0.3, 0.6]).is_nan());
+    }
+
+    #[test]
+    fn test_ln_pmf_length_mismatch() {
+        assert!(Multinomial::ln_pmf(&[2, 3], &[0.2, 0.3, 0.5]).is_nan());
+        assert!(Multinomial::ln_pmf(&[2, 3, 5], &[0.5, 0.5]).is_nan());
+        assert!(Multinomial::ln_pmf(&[], &[]).is_nan());
+    }
+
+    #[test]
+    fn test_ln_pmf_invalid_probs() {
+        assert!(Multinomial::ln_pmf(&[2, 3], &[f64::NAN, 0.5]).is_nan());
+        assert!(Multinomial::ln_pmf(&[2, 3], &[-0.5, 1.5]).is_nan());
+    }
+
+    #[test]
+    fn test_ln_pmf_total_overflow() {
+        assert!(Multinomial::ln_pmf(&[u64::MAX, 1], &[0.5, 0.5]).is_nan());
+    }
+}
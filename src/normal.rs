@@ -1,10 +1,65 @@
-use crate::math::{erf, fabs, log, pow, sqrt};
+use crate::erf::{erf, erfc};
+use crate::math::{exp, fabs, log, log1p, pow, sqrt};
+use crate::stats::{fit_by_moments, Family, Params};
+use crate::{ContinuousDistribution, FitMoments, ParamError};
 use core::f64::consts::{E, PI, SQRT_2};
 
+// 1 / sqrt(2 * PI), correctly rounded
+const FRAC_1_SQRT_2PI: f64 = 0.3989422804014327;
+
 /// The normal distribution.
-pub struct Normal;
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std_dev: f64,
+}
 
 impl Normal {
+    /// Creates a normal distribution with the given mean and standard deviation.
+    ///
+    /// Returns an error naming the parameter if `mean` is not finite or `std_dev` is not positive and finite.
+    pub fn new(mean: f64, std_dev: f64) -> Result<Self, ParamError> {
+        if !mean.is_finite() {
+            return Err(ParamError::NonFiniteMean);
+        }
+
+        if !std_dev.is_finite() {
+            return Err(ParamError::NonFiniteStdDev);
+        }
+
+        if std_dev <= 0.0 {
+            return Err(ParamError::NonPositiveStdDev);
+        }
+
+        Ok(Normal { mean, std_dev })
+    }
+
+    /// Creates a normal distribution with the given mean and precision (inverse variance).
+    ///
+    /// Returns an error naming the parameter if `mean` is not finite or `precision` is not positive and finite.
+    pub fn from_precision(mean: f64, precision: f64) -> Result<Self, ParamError> {
+        if !mean.is_finite() {
+            return Err(ParamError::NonFiniteMean);
+        }
+
+        if !precision.is_finite() {
+            return Err(ParamError::NonFinitePrecision);
+        }
+
+        if precision <= 0.0 {
+            return Err(ParamError::NonPositivePrecision);
+        }
+
+        Normal::new(mean, 1.0 / sqrt(precision))
+    }
+
+    /// Creates a normal distribution with the given mean and standard deviation without validating them.
+    ///
+    /// This is for hot paths where the parameters are already known to be valid. With invalid parameters, methods may return `NAN` or meaningless values.
+    pub fn new_unchecked(mean: f64, std_dev: f64) -> Self {
+        Normal { mean, std_dev }
+    }
+
     /// Returns the probability density function (PDF) of the normal distribution.
     pub fn pdf(x: f64, mean: f64, std_dev: f64) -> f64 {
         if std_dev <= 0.0 {
@@ -12,10 +67,24 @@ impl Normal {
         }
 
         let n = (x - mean) / std_dev;
-        (1.0 / (std_dev * sqrt(2.0 * PI))) * pow(E, -0.5 * n * n)
+        FRAC_1_SQRT_2PI / std_dev * pow(E, -0.5 * n * n)
+    }
+
+    /// Returns the natural logarithm of the probability density function (PDF) of the normal distribution.
+    ///
+    /// This stays finite in the far tails where the PDF underflows to zero.
+    pub fn ln_pdf(x: f64, mean: f64, std_dev: f64) -> f64 {
+        if std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        let n = (x - mean) / std_dev;
+        -0.5 * n * n - log(std_dev) - 0.5 * log(2.0 * PI)
     }
 
     /// Returns the cumulative distribution function (CDF) of the normal distribution.
+    ///
+    /// The result is non-decreasing in `x` and saturates at exactly `0.0` and `1.0` in the tails, so it is safe to use with bisection. Results are the same with and without `no_std`.
     pub fn cdf(x: f64, mean: f64, std_dev: f64) -> f64 {
         if std_dev <= 0.0 {
             return f64::NAN;
@@ -24,13 +93,101 @@ impl Normal {
         0.5 * (1.0 + erf((x - mean) / (std_dev * SQRT_2)))
     }
 
+    /// Returns the cumulative distribution function (CDF) of the standard normal distribution.
+    ///
+    /// This skips the `mean` and `std_dev` parameters of [`Normal::cdf`] and gives the same results.
+    #[inline]
+    pub fn std_cdf(z: f64) -> f64 {
+        0.5 * (1.0 + erf(z / SQRT_2))
+    }
+
+    /// Returns the natural logarithm of the cumulative distribution function (CDF) of the normal distribution.
+    ///
+    /// This stays finite in the far lower tail where the CDF underflows to zero.
+    pub fn ln_cdf(x: f64, mean: f64, std_dev: f64) -> f64 {
+        if x.is_nan() || mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        let z = (x - mean) / std_dev;
+        if z > -1.0 {
+            return log1p(-0.5 * erfc(z / SQRT_2));
+        }
+
+        if z > -37.0 {
+            return log(0.5 * erfc(-z / SQRT_2));
+        }
+
+        // asymptotic expansion of the Mills ratio, where erfc underflows
+        let w = 1.0 / (z * z);
+        let series = w * (1.0 - 3.0 * w * (1.0 - 5.0 * w * (1.0 - 7.0 * w)));
+        -0.5 * z * z - log(-z) - 0.5 * log(2.0 * PI) + log1p(-series)
+    }
+
+    /// Returns the survival function (SF) of the normal distribution.
+    ///
+    /// This is computed directly rather than as `1.0 - cdf`, so it stays accurate in the upper tail.
+    pub fn sf(x: f64, mean: f64, std_dev: f64) -> f64 {
+        if std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        0.5 * erfc((x - mean) / (std_dev * SQRT_2))
+    }
+
+    /// Returns an upper bound on the survival function (SF) of the standard normal distribution for `x > 0`.
+    ///
+    /// This is the Mills ratio bound `pdf(x) / x`, which is cheap to compute but is not the exact tail probability. Returns `NAN` if `x` is not positive.
+    pub fn upper_tail_bound(x: f64) -> f64 {
+        if x.is_nan() || x <= 0.0 {
+            return f64::NAN;
+        }
+
+        Normal::pdf(x, 0.0, 1.0) / x
+    }
+
+    /// Returns the short-term sigma level for a defect rate, including the conventional 1.5 sigma shift.
+    ///
+    /// Returns `NAN` if `defect_rate` is outside `(0, 1)`.
+    pub fn sigma_level(defect_rate: f64) -> f64 {
+        if defect_rate.is_nan() || defect_rate <= 0.0 || defect_rate >= 1.0 {
+            return f64::NAN;
+        }
+
+        Normal::isf(defect_rate, 0.0, 1.0) + 1.5
+    }
+
+    /// Returns the defect rate for a short-term sigma level, including the conventional 1.5 sigma shift.
+    ///
+    /// This is the inverse of [`Normal::sigma_level`].
+    pub fn defect_rate_for_sigma(sigma_level: f64) -> f64 {
+        if sigma_level.is_nan() {
+            return f64::NAN;
+        }
+
+        Normal::sf(sigma_level - 1.5, 0.0, 1.0)
+    }
+
     /// Returns the percent-point/quantile function (PPF) of the normal distribution.
+    pub fn ppf(p: f64, mean: f64, std_dev: f64) -> f64 {
+        if std_dev <= 0.0 || mean.is_nan() || std_dev.is_nan() {
+            return f64::NAN;
+        }
+
+        mean + std_dev * Normal::std_ppf(p)
+    }
+
     // Wichura, M. J. (1988).
     // Algorithm AS 241: The Percentage Points of the Normal Distribution.
     // Journal of the Royal Statistical Society. Series C (Applied Statistics), 37(3), 477-484.
+
+    /// Returns the percent-point/quantile function (PPF) of the standard normal distribution.
+    ///
+    /// This skips the `mean` and `std_dev` parameters of [`Normal::ppf`].
     #[allow(clippy::excessive_precision)]
-    pub fn ppf(p: f64, mean: f64, std_dev: f64) -> f64 {
-        if !(0.0..=1.0).contains(&p) || std_dev <= 0.0 || mean.is_nan() || std_dev.is_nan() {
+    #[inline]
+    pub fn std_ppf(p: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) {
             return f64::NAN;
         }
 
@@ -45,20 +202,18 @@ impl Normal {
         let q = p - 0.5;
         if fabs(q) < 0.425 {
             let r = 0.180625 - q * q;
-            mean + std_dev
-                * q
-                * (((((((2.5090809287301226727e3 * r + 3.3430575583588128105e4) * r
-                    + 6.7265770927008700853e4)
-                    * r
-                    + 4.5921953931549871457e4)
-                    * r
-                    + 1.3731693765509461125e4)
-                    * r
-                    + 1.9715909503065514427e3)
-                    * r
-                    + 1.3314166789178437745e2)
-                    * r
-                    + 3.3871328727963666080e0)
+            q * (((((((2.5090809287301226727e3 * r + 3.3430575583588128105e4) * r
+                + 6.7265770927008700853e4)
+                * r
+                + 4.5921953931549871457e4)
+                * r
+                + 1.3731693765509461125e4)
+                * r
+                + 1.9715909503065514427e3)
+                * r
+                + 1.3314166789178437745e2)
+                * r
+                + 3.3871328727963666080e0)
                 / (((((((5.2264952788528545610e3 * r + 2.8729085735721942674e4) * r
                     + 3.9307895800092710610e4)
                     * r
@@ -77,20 +232,18 @@ impl Normal {
             let sign = if q < 0.0 { -1.0 } else { 1.0 };
             if r < 5.0 {
                 r -= 1.6;
-                mean + std_dev
-                    * sign
-                    * (((((((7.74545014278341407640e-4 * r + 2.27238449892691845833e-2) * r
-                        + 2.41780725177450611770e-1)
-                        * r
-                        + 1.27045825245236838258e0)
-                        * r
-                        + 3.64784832476320460504e0)
-                        * r
-                        + 5.76949722146069140550e0)
-                        * r
-                        + 4.63033784615654529590e0)
-                        * r
-                        + 1.42343711074968357734e0)
+                sign * (((((((7.74545014278341407640e-4 * r + 2.27238449892691845833e-2) * r
+                    + 2.41780725177450611770e-1)
+                    * r
+                    + 1.27045825245236838258e0)
+                    * r
+                    + 3.64784832476320460504e0)
+                    * r
+                    + 5.76949722146069140550e0)
+                    * r
+                    + 4.63033784615654529590e0)
+                    * r
+                    + 1.42343711074968357734e0)
                     / (((((((1.05075007164441684324e-9 * r + 5.47593808499534494600e-4) * r
                         + 1.51986665636164571966e-2)
                         * r
@@ -105,20 +258,18 @@ impl Normal {
                         + 1.0)
             } else {
                 r -= 5.0;
-                mean + std_dev
-                    * sign
-                    * (((((((2.01033439929228813265e-7 * r + 2.71155556874348757815e-5) * r
-                        + 1.24266094738807843860e-3)
-                        * r
-                        + 2.65321895265761230930e-2)
-                        * r
-                        + 2.96560571828504891230e-1)
-                        * r
-                        + 1.78482653991729133580e0)
-                        * r
-                        + 5.46378491116411436990e0)
-                        * r
-                        + 6.65790464350110377720e0)
+                sign * (((((((2.01033439929228813265e-7 * r + 2.71155556874348757815e-5) * r
+                    + 1.24266094738807843860e-3)
+                    * r
+                    + 2.65321895265761230930e-2)
+                    * r
+                    + 2.96560571828504891230e-1)
+                    * r
+                    + 1.78482653991729133580e0)
+                    * r
+                    + 5.46378491116411436990e0)
+                    * r
+                    + 6.65790464350110377720e0)
                     / (((((((2.04426310338993978564e-15 * r + 1.42151175831644588870e-7) * r
                         + 1.84631831751005468180e-5)
                         * r
@@ -134,12 +285,315 @@ impl Normal {
             }
         }
     }
+
+    /// Returns the inverse survival function (ISF) of the normal distribution.
+    ///
+    /// This is computed directly rather than as `ppf(1.0 - p)`, so it stays accurate for small `p`.
+    pub fn isf(p: f64, mean: f64, std_dev: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) || std_dev <= 0.0 || mean.is_nan() || std_dev.is_nan() {
+            return f64::NAN;
+        }
+
+        mean - std_dev * Normal::std_ppf(p)
+    }
+
+    /// Returns the two-sided critical value of the normal distribution for significance level `alpha`.
+    ///
+    /// This is `ppf(1 - alpha / 2)`, computed without the subtraction so it stays accurate for tiny `alpha`.
+    pub fn critical_value(alpha: f64, mean: f64, std_dev: f64) -> f64 {
+        if !(0.0..=1.0).contains(&alpha) {
+            return f64::NAN;
+        }
+
+        Normal::isf(0.5 * alpha, mean, std_dev)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the normal distribution.
+    ///
+    /// Alias for [`Normal::ppf`].
+    pub fn quantile(p: f64, mean: f64, std_dev: f64) -> f64 {
+        Normal::ppf(p, mean, std_dev)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the normal distribution.
+    ///
+    /// Alias for [`Normal::ppf`].
+    pub fn inv_cdf(p: f64, mean: f64, std_dev: f64) -> f64 {
+        Normal::ppf(p, mean, std_dev)
+    }
+
+    /// Returns the probability density function (PDF) of the normal distribution in single precision.
+    ///
+    /// The result is computed in `f64` and rounded to `f32`.
+    pub fn pdf_f32(x: f32, mean: f32, std_dev: f32) -> f32 {
+        Normal::pdf(x as f64, mean as f64, std_dev as f64) as f32
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the normal distribution in single precision.
+    ///
+    /// The result is computed in `f64` and rounded to `f32`.
+    pub fn cdf_f32(x: f32, mean: f32, std_dev: f32) -> f32 {
+        Normal::cdf(x as f64, mean as f64, std_dev as f64) as f32
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the normal distribution in single precision.
+    ///
+    /// The result is computed in `f64` and rounded to `f32`.
+    pub fn ppf_f32(p: f32, mean: f32, std_dev: f32) -> f32 {
+        Normal::ppf(p as f64, mean as f64, std_dev as f64) as f32
+    }
+
+    /// Returns the survival function (SF) of the normal distribution.
+    ///
+    /// Alias for [`Normal::sf`].
+    pub fn survival(x: f64, mean: f64, std_dev: f64) -> f64 {
+        Normal::sf(x, mean, std_dev)
+    }
+
+    /// Returns the quantile with upper-tail probability `alpha` of the normal distribution.
+    ///
+    /// Alias for [`Normal::isf`]. Unlike `ppf(1.0 - alpha)`, this keeps full precision for tiny `alpha`.
+    pub fn upper_quantile(alpha: f64, mean: f64, std_dev: f64) -> f64 {
+        Normal::isf(alpha, mean, std_dev)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the normal distribution for each value in `ps`, writing the results to `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ps` and `out` have different lengths.
+    pub fn ppf_slice(ps: &[f64], mean: f64, std_dev: f64, out: &mut [f64]) {
+        assert_eq!(ps.len(), out.len(), "ps and out must have the same length");
+
+        for (p, o) in ps.iter().zip(out.iter_mut()) {
+            *o = Normal::ppf(*p, mean, std_dev);
+        }
+    }
+
+    /// Returns the quantiles of the normal distribution at probabilities 0.025, 0.25, 0.5, 0.75, and 0.975.
+    ///
+    /// These are the whiskers, quartiles, and median of a theoretical box plot.
+    pub fn five_number_summary(mean: f64, std_dev: f64) -> [f64; 5] {
+        if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return [f64::NAN; 5];
+        }
+
+        // symmetric, so only two quantiles are needed
+        let q = std_dev * Normal::ppf(0.75, 0.0, 1.0);
+        let w = std_dev * Normal::ppf(0.975, 0.0, 1.0);
+        [mean - w, mean - q, mean, mean + q, mean + w]
+    }
+
+    /// Returns the probability density function (PDF) of the normal distribution parameterized by precision (inverse variance).
+    pub fn pdf_precision(x: f64, mean: f64, precision: f64) -> f64 {
+        if precision.is_nan() || precision <= 0.0 {
+            return f64::NAN;
+        }
+
+        Normal::pdf(x, mean, 1.0 / sqrt(precision))
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the normal distribution parameterized by precision (inverse variance).
+    pub fn cdf_precision(x: f64, mean: f64, precision: f64) -> f64 {
+        if precision.is_nan() || precision <= 0.0 {
+            return f64::NAN;
+        }
+
+        Normal::cdf(x, mean, 1.0 / sqrt(precision))
+    }
+
+    /// Returns the second derivative of the probability density function (PDF) of the normal distribution.
+    ///
+    /// This is zero at the inflection points `mean - std_dev` and `mean + std_dev`.
+    pub fn d2pdf(x: f64, mean: f64, std_dev: f64) -> f64 {
+        if x.is_nan() || mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        let n = (x - mean) / std_dev;
+        if n.is_infinite() {
+            return 0.0;
+        }
+
+        Normal::pdf(x, mean, std_dev) * (n * n - 1.0) / (std_dev * std_dev)
+    }
+
+    /// Returns the mean of the normal distribution.
+    pub fn mean(mean: f64, std_dev: f64) -> f64 {
+        if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        mean
+    }
+
+    /// Returns the variance of the normal distribution.
+    pub fn variance(mean: f64, std_dev: f64) -> f64 {
+        if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        std_dev * std_dev
+    }
+
+    /// Returns the standard deviation of the normal distribution.
+    pub fn std_dev(mean: f64, std_dev: f64) -> f64 {
+        if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        std_dev
+    }
+
+    /// Returns the skewness of the normal distribution.
+    pub fn skewness(mean: f64, std_dev: f64) -> f64 {
+        if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        0.0
+    }
+
+    /// Returns the excess kurtosis of the normal distribution.
+    pub fn excess_kurtosis(mean: f64, std_dev: f64) -> f64 {
+        if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        0.0
+    }
+
+    /// Returns the differential entropy of the normal distribution in nats.
+    pub fn entropy(mean: f64, std_dev: f64) -> f64 {
+        if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        0.5 * log(2.0 * PI * E * std_dev * std_dev)
+    }
+
+    /// Returns the Kullback-Leibler divergence of `q` from `p` in nats.
+    ///
+    /// Returns `NAN` if either standard deviation is not positive, which can happen with `new_unchecked`.
+    pub fn kl_divergence(p: &Normal, q: &Normal) -> f64 {
+        if p.std_dev.is_nan() || q.std_dev.is_nan() || p.std_dev <= 0.0 || q.std_dev <= 0.0 {
+            return f64::NAN;
+        }
+
+        let diff = p.mean - q.mean;
+        log(q.std_dev / p.std_dev)
+            + (p.std_dev * p.std_dev + diff * diff) / (2.0 * q.std_dev * q.std_dev)
+            - 0.5
+    }
+
+    /// Returns the Anderson-Darling statistic for normality of `data` and its approximate p-value.
+    ///
+    /// The data is standardized with the sample mean and standard deviation, and the p-value uses the adjustment of D'Agostino and Stephens (1986) for estimated parameters, like R's `nortest::ad.test`. Returns `NAN` values if `data` has fewer than eight values or no variance. This takes linear time for sorted `data` and quadratic time otherwise, since it ranks the values without allocating.
+    pub fn anderson_darling(data: &[f64]) -> (f64, f64) {
+        if data.len() < 8 {
+            return (f64::NAN, f64::NAN);
+        }
+
+        let n = data.len() as f64;
+        let mean = data.iter().sum::<f64>() / n;
+        let variance = data.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / (n - 1.0);
+        if variance.is_nan() || variance <= 0.0 {
+            return (f64::NAN, f64::NAN);
+        }
+        let std_dev = sqrt(variance);
+
+        // the value of rank i contributes (2i - 1) ln(cdf) + (2n - 2i + 1) ln(1 - cdf),
+        // and tied values share a cdf, so the order among them does not matter
+        let sorted = data.windows(2).all(|w| w[0] <= w[1]);
+        let mut sum = 0.0;
+        for (i, x) in data.iter().enumerate() {
+            let rank = if sorted {
+                (i + 1) as f64
+            } else {
+                data.iter()
+                    .enumerate()
+                    .filter(|&(j, y)| y < x || (y == x && j < i))
+                    .count() as f64
+                    + 1.0
+            };
+            let z = (x - mean) / std_dev;
+            // lower tail of -z keeps precision for 1 - cdf
+            sum += (2.0 * rank - 1.0) * Normal::ln_cdf(z, 0.0, 1.0)
+                + (2.0 * (n - rank) + 1.0) * Normal::ln_cdf(-z, 0.0, 1.0);
+        }
+        let a2 = -n - sum / n;
+
+        let a = a2 * (1.0 + 0.75 / n + 2.25 / (n * n));
+        let p_value = if a < 0.2 {
+            1.0 - exp(-13.436 + 101.14 * a - 223.73 * a * a)
+        } else if a < 0.34 {
+            1.0 - exp(-8.318 + 42.796 * a - 59.938 * a * a)
+        } else if a < 0.6 {
+            exp(0.9177 - 4.279 * a - 1.38 * a * a)
+        } else {
+            exp(1.2937 - 5.709 * a + 0.0186 * a * a)
+        };
+        (a2, p_value)
+    }
+}
+
+impl ContinuousDistribution for Normal {
+    fn pdf(&self, x: f64) -> f64 {
+        Normal::pdf(x, self.mean, self.std_dev)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        Normal::cdf(x, self.mean, self.std_dev)
+    }
+
+    fn ppf(&self, p: f64) -> f64 {
+        Normal::ppf(p, self.mean, self.std_dev)
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        Normal::ln_pdf(x, self.mean, self.std_dev)
+    }
+
+    fn five_number_summary(&self) -> [f64; 5] {
+        Normal::five_number_summary(self.mean, self.std_dev)
+    }
+
+    fn entropy(&self) -> f64 {
+        Normal::entropy(self.mean, self.std_dev)
+    }
+}
+
+impl FitMoments for Normal {
+    fn fit_moments(data: &[f64]) -> Option<Self> {
+        match fit_by_moments(Family::Normal, data) {
+            Params::Normal { mean, std_dev } => Normal::new(mean, std_dev).ok(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "rand-distr-compat")]
+impl core::convert::TryFrom<Normal> for rand_distr::Normal<f64> {
+    type Error = rand_distr::NormalError;
+
+    fn try_from(normal: Normal) -> Result<Self, Self::Error> {
+        // rand_distr accepts a negative std_dev, which new_unchecked could let through
+        if normal.std_dev.is_nan() || normal.std_dev <= 0.0 {
+            return Err(rand_distr::NormalError::BadVariance);
+        }
+
+        rand_distr::Normal::new(normal.mean, normal.std_dev)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Normal;
-    use core::f64::{INFINITY, NEG_INFINITY};
+    use crate::math::{log, sqrt};
+    use crate::{ContinuousDistribution, FitMoments, ParamError};
+    use core::f64::consts::{PI, SQRT_2};
+    const INFINITY: f64 = f64::INFINITY;
+    const NEG_INFINITY: f64 = f64::NEG_INFINITY;
 
     fn assert_in_delta(act: f64, exp: f64, delta: f64) {
         if exp.is_finite() {
@@ -149,6 +603,10 @@ mod tests {
         }
     }
 
+    fn assert_in_relative_delta(act: f64, exp: f64, delta: f64) {
+        assert!(((exp - act) / exp).abs() < delta, "{} != {}", act, exp);
+    }
+
     #[test]
     fn test_pdf() {
         let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
@@ -160,6 +618,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pdf_zero() {
+        // one ulp of the constant at 0.4 is EPSILON / 4
+        let act = Normal::pdf(0.0, 0.0, 1.0);
+        assert!((act - 1.0 / sqrt(2.0 * PI)).abs() <= 0.25 * f64::EPSILON);
+        assert_eq!(act, 0.3989422804014327);
+        assert_eq!(Normal::pdf(1.0, 1.0, 0.5), 2.0 * 0.3989422804014327);
+    }
+
     #[test]
     fn test_pdf_mean_std_dev() {
         let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
@@ -190,53 +657,259 @@ mod tests {
     }
 
     #[test]
-    fn test_pdf_zero_std_dev() {
-        assert!(Normal::pdf(0.0, 0.0, 0.0).is_nan());
+    fn test_ln_pdf() {
+        let inputs = [NEG_INFINITY, -3.0, -1.0, 0.0, 1.0, 3.0, INFINITY];
+        let expected = [
+            NEG_INFINITY,
+            -3.61209,
+            -2.11209,
+            -1.73709,
+            -1.61209,
+            -2.11209,
+            NEG_INFINITY,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Normal::ln_pdf(*input, 1.0, 2.0), exp, 0.00001);
+        }
     }
 
     #[test]
-    fn test_pdf_negative_std_dev() {
-        assert!(Normal::pdf(0.0, 0.0, -1.0).is_nan());
+    fn test_ln_pdf_tail() {
+        assert_in_delta(
+            Normal::ln_pdf(100.0, 0.0, 1.0),
+            -5000.918938533205,
+            0.000000001,
+        );
+        assert_in_delta(
+            Normal::ln_pdf(-100.0, 0.0, 1.0),
+            -5000.918938533205,
+            0.000000001,
+        );
     }
 
     #[test]
-    fn test_cdf() {
-        let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
+    fn test_ln_pdf_invalid() {
+        assert!(Normal::ln_pdf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Normal::ln_pdf(0.0, f64::NAN, 1.0).is_nan());
+        assert!(Normal::ln_pdf(0.0, 0.0, f64::NAN).is_nan());
+        assert!(Normal::ln_pdf(0.0, 0.0, 0.0).is_nan());
+        assert!(Normal::ln_pdf(0.0, 0.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let inputs = [-10.0, -5.0, -1.0, 0.5, 3.0, 10.0];
         let expected = [
-            0.0, 0.00135, 0.02275, 0.15866, 0.5, 0.84134, 0.97725, 0.99865, 1.0,
+            -53.23128515051247,
+            -15.064998393988726,
+            -1.8410216450092635,
+            -0.3689464152886564,
+            -0.0013508099647481938,
+            -7.619853024160526e-24,
         ];
         for (input, exp) in inputs.iter().zip(expected) {
-            assert_in_delta(Normal::cdf(*input, 0.0, 1.0), exp, 0.00001);
+            assert_in_relative_delta(Normal::ln_cdf(*input, 0.0, 1.0), exp, 1e-13);
         }
     }
 
     #[test]
-    fn test_cdf_mean_std_dev() {
-        let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
+    fn test_ln_cdf_tail() {
+        // cdf underflows to zero below about -38.5
+        let inputs = [-30.0, -37.5, -40.0, -100.0, -1e5];
         let expected = [
-            0.0, 0.02275, 0.06681, 0.15866, 0.30854, 0.5, 0.69146, 0.84134, 1.0,
+            -454.3212439563432,
+            -707.6689893175072,
+            -804.6084420137538,
+            -5005.524208694205,
+            -5000000012.431864,
         ];
         for (input, exp) in inputs.iter().zip(expected) {
-            assert_in_delta(Normal::cdf(*input, 1.0, 2.0), exp, 0.00001);
+            assert_in_relative_delta(Normal::ln_cdf(*input, 0.0, 1.0), exp, 1e-13);
         }
+        assert_eq!(Normal::cdf(-40.0, 0.0, 1.0), 0.0);
     }
 
     #[test]
-    fn test_cdf_infinite_mean() {
-        assert_in_delta(Normal::cdf(1.0, NEG_INFINITY, 1.0), 1.0, 0.00001);
-        assert_in_delta(Normal::cdf(1.0, INFINITY, 1.0), 0.0, 0.00001);
+    fn test_ln_cdf_continuous() {
+        // matches on both sides of the branch points
+        for z in [-1.0, -37.0] {
+            let below = Normal::ln_cdf(z - 1e-12, 0.0, 1.0);
+            let above = Normal::ln_cdf(z + 1e-12, 0.0, 1.0);
+            assert_in_relative_delta(below, above, 1e-11);
+        }
     }
 
     #[test]
-    fn test_cdf_infinite_std_dev() {
-        assert_in_delta(Normal::cdf(1.0, 0.0, INFINITY), 0.5, 0.00001);
+    fn test_ln_cdf_mean_std_dev() {
+        assert_in_relative_delta(
+            Normal::ln_cdf(-59.0, 1.0, 2.0),
+            Normal::ln_cdf(-30.0, 0.0, 1.0),
+            1e-15,
+        );
     }
 
     #[test]
-    fn test_cdf_nan() {
-        assert!(Normal::cdf(f64::NAN, 0.0, 1.0).is_nan());
-        assert!(Normal::cdf(0.0, f64::NAN, 1.0).is_nan());
-        assert!(Normal::cdf(0.0, 0.0, f64::NAN).is_nan());
+    fn test_ln_cdf_infinity() {
+        assert_eq!(Normal::ln_cdf(NEG_INFINITY, 0.0, 1.0), NEG_INFINITY);
+        assert_eq!(Normal::ln_cdf(INFINITY, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_ln_cdf_invalid() {
+        assert!(Normal::ln_cdf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Normal::ln_cdf(0.0, f64::NAN, 1.0).is_nan());
+        assert!(Normal::ln_cdf(0.0, 0.0, 0.0).is_nan());
+        assert!(Normal::ln_cdf(0.0, 0.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_zero_std_dev() {
+        assert!(Normal::pdf(0.0, 0.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_pdf_negative_std_dev() {
+        assert!(Normal::pdf(0.0, 0.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
+        let expected = [
+            0.0, 0.00135, 0.02275, 0.15866, 0.5, 0.84134, 0.97725, 0.99865, 1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Normal::cdf(*input, 0.0, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_mean_std_dev() {
+        let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
+        let expected = [
+            0.0, 0.02275, 0.06681, 0.15866, 0.30854, 0.5, 0.69146, 0.84134, 1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Normal::cdf(*input, 1.0, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_infinite_mean() {
+        assert_in_delta(Normal::cdf(1.0, NEG_INFINITY, 1.0), 1.0, 0.00001);
+        assert_in_delta(Normal::cdf(1.0, INFINITY, 1.0), 0.0, 0.00001);
+    }
+
+    #[test]
+    fn test_cdf_infinite_std_dev() {
+        assert_in_delta(Normal::cdf(1.0, 0.0, INFINITY), 0.5, 0.00001);
+    }
+
+    #[test]
+    fn test_cdf_monotone() {
+        let mut prev = 0.0;
+        for i in 0..=800000 {
+            let x = -40.0 + 0.0001 * i as f64;
+            let act = Normal::cdf(x, 0.0, 1.0);
+            assert!(act >= prev, "{} < {} at {}", act, prev, x);
+            prev = act;
+        }
+    }
+
+    #[test]
+    fn test_cdf_monotone_adjacent() {
+        fn next_up(x: f64) -> f64 {
+            f64::from_bits(if x > 0.0 {
+                x.to_bits() + 1
+            } else {
+                x.to_bits() - 1
+            })
+        }
+
+        fn next_down(x: f64) -> f64 {
+            f64::from_bits(if x > 0.0 {
+                x.to_bits() - 1
+            } else {
+                x.to_bits() + 1
+            })
+        }
+
+        // the branch points of erf are on its argument, which is x / sqrt(2)
+        let branches = [
+            0x3e30000000000000, // 2^-28
+            0x3feb000000000000, // 0.84375
+            0x3ff4000000000000, // 1.25
+            0x4006db6d00000000, // about 1 / 0.35
+            0x4018000000000000, // 6
+        ];
+        for bits in branches {
+            let branch = f64::from_bits(bits) * SQRT_2;
+            for center in [-branch, branch] {
+                // step one float at a time across the branch point
+                let mut x = center;
+                for _ in 0..100000 {
+                    x = next_down(x);
+                }
+                let mut prev = Normal::cdf(x, 0.0, 1.0);
+                for _ in 0..200000 {
+                    x = next_up(x);
+                    let act = Normal::cdf(x, 0.0, 1.0);
+                    assert!(act >= prev, "{} < {} at {}", act, prev, x);
+                    prev = act;
+                }
+                assert!(x > center);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cdf_bits() {
+        // same bits with and without no_std
+        let inputs = [-6.0, -3.5, -1.1, -0.3, 0.2, 0.9, 1.7, 2.6, 4.2];
+        let expected = [
+            0x3e10f30ef0000000,
+            0x3f2e7dbc92b78000,
+            0x3fc15d8167788ec0,
+            0x3fd87423a677e90f,
+            0x3fe2894ba87dce1c,
+            0x3fea1c2df0a46a8e,
+            0x3fee92eb735d7870,
+            0x3fefd9d0c7bc1579,
+            0x3fefffe4030e38d2,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_eq!(Normal::cdf(*input, 0.0, 1.0).to_bits(), exp);
+        }
+    }
+
+    #[test]
+    fn test_std_cdf() {
+        assert_eq!(Normal::std_cdf(0.0), 0.5);
+        assert_eq!(Normal::std_cdf(NEG_INFINITY), 0.0);
+        assert_eq!(Normal::std_cdf(INFINITY), 1.0);
+        assert!(Normal::std_cdf(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_std_cdf_cdf() {
+        for z in [-40.0, -6.0, -3.5, -1.1, -0.3, 0.2, 0.9, 1.7, 2.6, 4.2, 40.0] {
+            assert_eq!(Normal::std_cdf(z), Normal::cdf(z, 0.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn test_cdf_saturates() {
+        assert_eq!(Normal::cdf(-40.0, 0.0, 1.0), 0.0);
+        assert_eq!(Normal::cdf(40.0, 0.0, 1.0), 1.0);
+        assert_eq!(Normal::cdf(f64::MAX, 0.0, 1.0), 1.0);
+        assert_eq!(Normal::cdf(f64::MIN, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(Normal::cdf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Normal::cdf(0.0, f64::NAN, 1.0).is_nan());
+        assert!(Normal::cdf(0.0, 0.0, f64::NAN).is_nan());
     }
 
     #[test]
@@ -249,6 +922,165 @@ mod tests {
         assert!(Normal::cdf(0.0, 0.0, -1.0).is_nan());
     }
 
+    #[test]
+    fn test_sf() {
+        let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
+        let expected = [
+            1.0, 0.99865, 0.97725, 0.84134, 0.5, 0.15866, 0.02275, 0.00135, 0.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Normal::sf(*input, 0.0, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_sf_tail() {
+        let act = Normal::sf(10.0, 0.0, 1.0);
+        assert!((act - 7.619853024160525e-24).abs() / act < 0.000000000001);
+
+        let act = Normal::sf(21.0, 1.0, 2.0);
+        assert!((act - 7.619853024160525e-24).abs() / act < 0.000000000001);
+    }
+
+    #[test]
+    fn test_sf_nan() {
+        assert!(Normal::sf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Normal::sf(0.0, f64::NAN, 1.0).is_nan());
+        assert!(Normal::sf(0.0, 0.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_sf_non_positive_std_dev() {
+        assert!(Normal::sf(0.0, 0.0, 0.0).is_nan());
+        assert!(Normal::sf(0.0, 0.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_sf_aliases() {
+        for x in [-2.0, 0.0, 1.5, 10.0] {
+            assert_eq!(Normal::survival(x, 1.0, 2.0), Normal::sf(x, 1.0, 2.0));
+        }
+    }
+
+    #[test]
+    fn test_upper_tail_bound() {
+        for i in 1..=400 {
+            let x = 0.025 * i as f64;
+            assert!(Normal::upper_tail_bound(x) >= Normal::sf(x, 0.0, 1.0));
+        }
+        assert_in_delta(Normal::upper_tail_bound(1.0), 0.24197, 0.00001);
+        assert_in_delta(Normal::upper_tail_bound(INFINITY), 0.0, 0.00001);
+    }
+
+    #[test]
+    fn test_upper_tail_bound_tight() {
+        // bound approaches the exact value as x grows
+        let ratio = Normal::upper_tail_bound(20.0) / Normal::sf(20.0, 0.0, 1.0);
+        assert!(ratio > 1.0 && ratio < 1.003);
+    }
+
+    #[test]
+    fn test_upper_tail_bound_invalid() {
+        assert!(Normal::upper_tail_bound(0.0).is_nan());
+        assert!(Normal::upper_tail_bound(-1.0).is_nan());
+        assert!(Normal::upper_tail_bound(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_isf() {
+        let inputs = [0.0, 0.01, 0.1, 0.5, 0.9, 0.99, 1.0];
+        let expected = [
+            INFINITY,
+            2.32635,
+            1.28155,
+            0.0,
+            -1.28155,
+            -2.32635,
+            NEG_INFINITY,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Normal::isf(*input, 0.0, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_isf_mean_std_dev() {
+        for p in [0.01, 0.1, 0.5, 0.9, 0.99] {
+            assert_in_delta(
+                Normal::isf(p, 1.0, 2.0),
+                Normal::ppf(1.0 - p, 1.0, 2.0),
+                0.000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_isf_tail() {
+        assert_in_delta(Normal::isf(1e-6, 0.0, 1.0), 4.753424308822899, 0.000000001);
+        assert_in_delta(Normal::isf(1e-20, 0.0, 1.0), 9.262340089798153, 0.000000001);
+        assert_in_delta(
+            Normal::sf(Normal::isf(7.619853024160525e-24, 0.0, 1.0), 0.0, 1.0)
+                / 7.619853024160525e-24,
+            1.0,
+            0.000000001,
+        );
+    }
+
+    #[test]
+    fn test_upper_quantile() {
+        let inputs = [1e-12, 1e-9];
+        let expected = [7.034483825301132, 5.997807015007687];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(Normal::upper_quantile(*input, 0.0, 1.0), exp, 1e-14);
+        }
+
+        // precision is lost when computing 1 - alpha
+        assert!((Normal::ppf(1.0 - 1e-12, 0.0, 1.0) - 7.034483825301132).abs() > 1e-7);
+    }
+
+    #[test]
+    fn test_upper_quantile_alias() {
+        for alpha in [0.0, 1e-12, 0.05, 0.5, 1.0] {
+            assert_eq!(
+                Normal::upper_quantile(alpha, 1.0, 2.0),
+                Normal::isf(alpha, 1.0, 2.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_critical_value() {
+        let inputs = [1e-12, 0.05];
+        let expected = [7.130506848171325, 1.959963984540054];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(Normal::critical_value(*input, 0.0, 1.0), exp, 1e-14);
+        }
+        assert_in_delta(
+            Normal::critical_value(0.05, 1.0, 2.0),
+            1.0 + 2.0 * 1.959963984540054,
+            0.000000000001,
+        );
+        assert_eq!(Normal::critical_value(0.0, 0.0, 1.0), f64::INFINITY);
+        assert_eq!(Normal::critical_value(1.0, 0.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_critical_value_invalid() {
+        assert!(Normal::critical_value(-0.1, 0.0, 1.0).is_nan());
+        assert!(Normal::critical_value(1.1, 0.0, 1.0).is_nan());
+        assert!(Normal::critical_value(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Normal::critical_value(0.05, 0.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_isf_invalid() {
+        assert!(Normal::isf(-0.1, 0.0, 1.0).is_nan());
+        assert!(Normal::isf(1.1, 0.0, 1.0).is_nan());
+        assert!(Normal::isf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Normal::isf(0.5, f64::NAN, 1.0).is_nan());
+        assert!(Normal::isf(0.5, 0.0, 0.0).is_nan());
+    }
+
     #[test]
     fn test_ppf() {
         let inputs = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
@@ -332,4 +1164,494 @@ mod tests {
     fn test_ppf_negative_std_dev() {
         assert!(Normal::ppf(0.5, 0.0, -1.0).is_nan());
     }
+
+    #[test]
+    fn test_std_ppf() {
+        assert_eq!(Normal::std_ppf(0.5), 0.0);
+        assert_eq!(Normal::std_ppf(0.0), NEG_INFINITY);
+        assert_eq!(Normal::std_ppf(1.0), INFINITY);
+        assert!(Normal::std_ppf(-0.1).is_nan());
+        assert!(Normal::std_ppf(1.1).is_nan());
+        assert!(Normal::std_ppf(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_std_ppf_ppf() {
+        for p in [
+            1e-300,
+            1e-20,
+            0.001,
+            0.1,
+            0.25,
+            0.5,
+            0.7,
+            0.9,
+            0.999,
+            1.0 - 1e-12,
+        ] {
+            assert_eq!(Normal::std_ppf(p), Normal::ppf(p, 0.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn test_ppf_slice() {
+        let ps = [0.0, 0.1, 0.5, 0.9, 1.0, -1.0, 2.0, f64::NAN];
+        let mut out = [0.0; 8];
+        Normal::ppf_slice(&ps, 1.0, 2.0, &mut out);
+        let expected = [NEG_INFINITY, -1.5631, 1.0, 3.5631, INFINITY];
+        for (act, exp) in out.iter().zip(expected) {
+            assert_in_delta(*act, exp, 0.00001);
+        }
+        assert!(out[5].is_nan());
+        assert!(out[6].is_nan());
+        assert!(out[7].is_nan());
+    }
+
+    #[test]
+    #[should_panic(expected = "ps and out must have the same length")]
+    fn test_ppf_slice_different_lengths() {
+        Normal::ppf_slice(&[0.5, 0.5], 0.0, 1.0, &mut [0.0]);
+    }
+
+    #[test]
+    fn test_five_number_summary() {
+        let expected = [
+            -2.9199279690801084,
+            -0.3489795003921635,
+            1.0,
+            2.3489795003921635,
+            4.919927969080108,
+        ];
+        for (act, exp) in Normal::five_number_summary(1.0, 2.0).iter().zip(expected) {
+            assert_in_delta(*act, exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_five_number_summary_ppf() {
+        let ps = [0.025, 0.25, 0.5, 0.75, 0.975];
+        for (act, p) in Normal::five_number_summary(1.0, 2.0).iter().zip(ps) {
+            assert_in_delta(*act, Normal::ppf(p, 1.0, 2.0), 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_five_number_summary_invalid() {
+        assert!(Normal::five_number_summary(1.0, 0.0)
+            .iter()
+            .all(|v| v.is_nan()));
+        assert!(Normal::five_number_summary(1.0, -1.0)
+            .iter()
+            .all(|v| v.is_nan()));
+        assert!(Normal::five_number_summary(f64::NAN, 1.0)
+            .iter()
+            .all(|v| v.is_nan()));
+        assert!(Normal::five_number_summary(1.0, f64::NAN)
+            .iter()
+            .all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_pdf_precision() {
+        for x in [NEG_INFINITY, -3.0, -1.0, 0.0, 1.0, 2.5, INFINITY] {
+            assert_in_delta(
+                Normal::pdf_precision(x, 1.0, 0.25),
+                Normal::pdf(x, 1.0, 2.0),
+                0.000000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_pdf_precision_invalid() {
+        assert!(Normal::pdf_precision(0.0, 0.0, 0.0).is_nan());
+        assert!(Normal::pdf_precision(0.0, 0.0, -1.0).is_nan());
+        assert!(Normal::pdf_precision(0.0, 0.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_precision() {
+        for x in [NEG_INFINITY, -3.0, -1.0, 0.0, 1.0, 2.5, INFINITY] {
+            assert_in_delta(
+                Normal::cdf_precision(x, 1.0, 0.25),
+                Normal::cdf(x, 1.0, 2.0),
+                0.000000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_cdf_precision_invalid() {
+        assert!(Normal::cdf_precision(0.0, 0.0, 0.0).is_nan());
+        assert!(Normal::cdf_precision(0.0, 0.0, -1.0).is_nan());
+        assert!(Normal::cdf_precision(0.0, 0.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_aliases() {
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(Normal::quantile(p, 1.0, 2.0), Normal::ppf(p, 1.0, 2.0));
+            assert_eq!(Normal::inv_cdf(p, 1.0, 2.0), Normal::ppf(p, 1.0, 2.0));
+        }
+    }
+
+    #[test]
+    fn test_d2pdf() {
+        let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
+        let expected = [
+            0.0, 0.03545, 0.16197, 0.0, -0.39894, 0.0, 0.16197, 0.03545, 0.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Normal::d2pdf(*input, 0.0, 1.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_d2pdf_mean_std_dev() {
+        let inputs = [-3.0, -1.0, 0.0, 1.0, 3.0];
+        let expected = [0.02025, 0.0, -0.03301, -0.04987, 0.0];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Normal::d2pdf(*input, 1.0, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_d2pdf_inflection_points() {
+        assert_eq!(Normal::d2pdf(1.0, 3.0, 2.0), 0.0);
+        assert_eq!(Normal::d2pdf(5.0, 3.0, 2.0), 0.0);
+        assert_eq!(Normal::d2pdf(-1.5, 0.0, 1.5), 0.0);
+        assert_eq!(Normal::d2pdf(1.5, 0.0, 1.5), 0.0);
+    }
+
+    #[test]
+    fn test_d2pdf_invalid() {
+        assert!(Normal::d2pdf(f64::NAN, 0.0, 1.0).is_nan());
+        assert!(Normal::d2pdf(0.0, f64::NAN, 1.0).is_nan());
+        assert!(Normal::d2pdf(0.0, 0.0, f64::NAN).is_nan());
+        assert!(Normal::d2pdf(0.0, 0.0, 0.0).is_nan());
+        assert!(Normal::d2pdf(0.0, 0.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_moments() {
+        assert_eq!(Normal::mean(1.5, 2.0), 1.5);
+        assert_eq!(Normal::variance(1.5, 2.0), 4.0);
+        assert_eq!(Normal::std_dev(1.5, 2.0), 2.0);
+        assert_eq!(Normal::skewness(1.5, 2.0), 0.0);
+        assert_eq!(Normal::excess_kurtosis(1.5, 2.0), 0.0);
+    }
+
+    #[test]
+    fn test_moments_invalid() {
+        for (mean, std_dev) in [(0.0, 0.0), (0.0, -1.0), (f64::NAN, 1.0), (0.0, f64::NAN)] {
+            assert!(Normal::mean(mean, std_dev).is_nan());
+            assert!(Normal::variance(mean, std_dev).is_nan());
+            assert!(Normal::std_dev(mean, std_dev).is_nan());
+            assert!(Normal::skewness(mean, std_dev).is_nan());
+            assert!(Normal::excess_kurtosis(mean, std_dev).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_entropy() {
+        assert_in_delta(Normal::entropy(1.0, 2.0), 2.112085713764618, 0.000000000001);
+        assert_in_delta(
+            Normal::new(1.0, 2.0).unwrap().entropy(),
+            2.112085713764618,
+            0.000000000001,
+        );
+    }
+
+    #[test]
+    fn test_entropy_invalid() {
+        assert!(Normal::entropy(1.0, 0.0).is_nan());
+        assert!(Normal::entropy(1.0, -1.0).is_nan());
+        assert!(Normal::entropy(f64::NAN, 1.0).is_nan());
+        assert!(Normal::entropy(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_kl_divergence() {
+        let p = Normal::new(1.0, 2.0).unwrap();
+        let q = Normal::new(-1.0, 3.0).unwrap();
+        assert_in_delta(
+            Normal::kl_divergence(&p, &q),
+            0.3499095525526088,
+            0.000000000001,
+        );
+        assert_in_delta(
+            Normal::kl_divergence(&q, &p),
+            0.7195348918918356,
+            0.000000000001,
+        );
+    }
+
+    #[test]
+    fn test_kl_divergence_formula() {
+        let params = [(0.0, 1.0), (1.0, 2.0), (-3.0, 0.1), (10.0, 50.0)];
+        for (mp, sp) in params {
+            for (mq, sq) in params {
+                let p = Normal::new(mp, sp).unwrap();
+                let q = Normal::new(mq, sq).unwrap();
+                let exp = log(sq / sp) + (sp * sp + (mp - mq) * (mp - mq)) / (2.0 * sq * sq) - 0.5;
+                assert_in_delta(Normal::kl_divergence(&p, &q), exp, 0.000000001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kl_divergence_same() {
+        let p = Normal::new(1.0, 2.0).unwrap();
+        assert_eq!(Normal::kl_divergence(&p, &p), 0.0);
+    }
+
+    #[test]
+    fn test_kl_divergence_invalid() {
+        let valid = Normal::new(0.0, 1.0).unwrap();
+        for std_dev in [0.0, -1.0] {
+            let invalid = Normal::new_unchecked(0.0, std_dev);
+            assert!(Normal::kl_divergence(&valid, &invalid).is_nan());
+            assert!(Normal::kl_divergence(&invalid, &valid).is_nan());
+            assert!(Normal::kl_divergence(&invalid, &invalid).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_anderson_darling() {
+        let data = [
+            2.1, -0.3, 1.4, 0.8, -1.2, 0.5, 0.0, 1.9, -0.7, 0.3, 1.1, -0.1,
+        ];
+        let (a2, p_value) = Normal::anderson_darling(&data);
+        assert_in_delta(a2, 0.12381151377564854, 0.000000000001);
+        assert_in_delta(p_value, 0.9801952322895455, 0.000000000001);
+    }
+
+    #[test]
+    fn test_anderson_darling_p_value() {
+        // covers each piece of the p-value approximation
+        let expected = [
+            (10.0, 0.14110924785979434, 0.9566579384676889),
+            (14.0, 0.21508830668589268, 0.7889350148242812),
+            (18.0, 0.5110183250591215, 0.14716264780602392),
+            (22.0, 0.8231807475311622, 0.021303955726639207),
+        ];
+        for (last, exp_a2, exp_p) in expected {
+            let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, last];
+            let (a2, p_value) = Normal::anderson_darling(&data);
+            assert_in_delta(a2, exp_a2, 0.000000000001);
+            assert_in_delta(p_value, exp_p, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_anderson_darling_skewed() {
+        let data = [
+            1.0, 1.0, 1.0, 2.0, 2.0, 3.0, 4.0, 6.0, 9.0, 15.0, 30.0, 70.0,
+        ];
+        let (a2, p_value) = Normal::anderson_darling(&data);
+        assert_in_delta(a2, 1.9087222298381301, 0.000000000001);
+        assert_in_delta(p_value, 3.117950598488449e-05, 0.000000000001);
+
+        // ties in unsorted data
+        let shuffled = [
+            30.0, 1.0, 4.0, 2.0, 70.0, 1.0, 9.0, 3.0, 2.0, 15.0, 1.0, 6.0,
+        ];
+        let (shuffled_a2, shuffled_p_value) = Normal::anderson_darling(&shuffled);
+        assert_in_delta(shuffled_a2, a2, 0.000000000001);
+        assert_in_delta(shuffled_p_value, p_value, 0.000000000001);
+    }
+
+    #[test]
+    fn test_anderson_darling_order() {
+        let data = [0.2, 0.3, 0.25, 0.28, 0.9, 0.95, 0.97, 0.99];
+        let sorted = [0.2, 0.25, 0.28, 0.3, 0.9, 0.95, 0.97, 0.99];
+        let (a2, p_value) = Normal::anderson_darling(&data);
+        assert_in_delta(a2, 0.9369722556371904, 0.000000000001);
+        assert_in_delta(p_value, 0.008877758601406554, 0.000000000001);
+        let (sorted_a2, sorted_p_value) = Normal::anderson_darling(&sorted);
+        assert_in_delta(sorted_a2, a2, 0.000000000001);
+        assert_in_delta(sorted_p_value, p_value, 0.000000000001);
+    }
+
+    #[test]
+    fn test_anderson_darling_invalid() {
+        let (a2, p_value) = Normal::anderson_darling(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+        assert!(a2.is_nan() && p_value.is_nan());
+        let (a2, p_value) = Normal::anderson_darling(&[2.0; 10]);
+        assert!(a2.is_nan() && p_value.is_nan());
+        let (a2, p_value) =
+            Normal::anderson_darling(&[1.0, 2.0, 3.0, f64::NAN, 5.0, 6.0, 7.0, 8.0]);
+        assert!(a2.is_nan() && p_value.is_nan());
+    }
+
+    #[test]
+    fn test_from_precision() {
+        let normal = Normal::from_precision(1.0, 0.25).unwrap();
+        assert_eq!(normal, Normal::new(1.0, 2.0).unwrap());
+        assert_eq!(normal.pdf(0.5), Normal::pdf_precision(0.5, 1.0, 0.25));
+    }
+
+    #[test]
+    fn test_from_precision_invalid() {
+        assert_eq!(
+            Normal::from_precision(0.0, 0.0),
+            Err(ParamError::NonPositivePrecision)
+        );
+        assert_eq!(
+            Normal::from_precision(0.0, -1.0),
+            Err(ParamError::NonPositivePrecision)
+        );
+        assert_eq!(
+            Normal::from_precision(0.0, f64::NAN),
+            Err(ParamError::NonFinitePrecision)
+        );
+        assert_eq!(
+            Normal::from_precision(0.0, INFINITY),
+            Err(ParamError::NonFinitePrecision)
+        );
+        assert_eq!(
+            Normal::from_precision(f64::NAN, 1.0),
+            Err(ParamError::NonFiniteMean)
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Normal::new(1.0, 2.0).is_ok());
+        assert_eq!(Normal::new(0.0, -1.0), Err(ParamError::NonPositiveStdDev));
+        assert_eq!(Normal::new(1.0, 0.0), Err(ParamError::NonPositiveStdDev));
+        assert_eq!(Normal::new(1.0, f64::NAN), Err(ParamError::NonFiniteStdDev));
+        assert_eq!(Normal::new(1.0, INFINITY), Err(ParamError::NonFiniteStdDev));
+        assert_eq!(Normal::new(f64::NAN, 1.0), Err(ParamError::NonFiniteMean));
+        assert_eq!(Normal::new(INFINITY, -1.0), Err(ParamError::NonFiniteMean));
+    }
+
+    #[test]
+    fn test_new_unchecked() {
+        assert_eq!(
+            Normal::new_unchecked(1.0, 2.0),
+            Normal::new(1.0, 2.0).unwrap()
+        );
+        assert!(Normal::new_unchecked(1.0, -2.0).pdf(0.0).is_nan());
+    }
+
+    #[test]
+    fn test_new_unchecked_invalid() {
+        for std_dev in [0.0, -2.0, f64::NAN] {
+            let normal = Normal::new_unchecked(1.0, std_dev);
+            assert!(normal.pdf(0.0).is_nan());
+            assert!(normal.ln_pdf(0.0).is_nan());
+            assert!(normal.cdf(0.0).is_nan());
+            assert!(normal.ppf(0.5).is_nan());
+            assert!(normal.entropy().is_nan());
+            assert!(normal.five_number_summary().iter().all(|x| x.is_nan()));
+        }
+    }
+
+    #[test]
+    fn test_fit_moments() {
+        let mut data = [0.0; 1000];
+        for (i, x) in data.iter_mut().enumerate() {
+            *x = Normal::ppf((i as f64 + 0.5) / 1000.0, 3.0, 2.0);
+        }
+        let normal = Normal::fit_moments(&data).unwrap();
+        assert_in_delta(normal.mean, 3.0, 0.000001);
+        assert_in_delta(normal.std_dev, 2.0, 0.01);
+    }
+
+    #[test]
+    fn test_fit_moments_degenerate() {
+        assert!(Normal::fit_moments(&[]).is_none());
+        assert!(Normal::fit_moments(&[1.0]).is_none());
+        assert!(Normal::fit_moments(&[2.0, 2.0, 2.0]).is_none());
+        assert!(Normal::fit_moments(&[1.0, f64::NAN]).is_none());
+    }
+
+    #[test]
+    fn test_continuous_distribution() {
+        let dist = Normal::new(1.0, 2.0).unwrap();
+        for x in [-2.0, 0.0, 0.5, 3.0] {
+            assert_eq!(
+                ContinuousDistribution::pdf(&dist, x),
+                Normal::pdf(x, 1.0, 2.0)
+            );
+            assert_eq!(
+                ContinuousDistribution::cdf(&dist, x),
+                Normal::cdf(x, 1.0, 2.0)
+            );
+        }
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(
+                ContinuousDistribution::ppf(&dist, p),
+                Normal::ppf(p, 1.0, 2.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_sigma_level() {
+        assert_in_delta(Normal::sigma_level(3.4e-6), 6.0, 0.001);
+        assert_in_delta(Normal::sigma_level(0.00621), 4.0, 0.001);
+        assert_in_delta(Normal::sigma_level(0.5), 1.5, 0.000001);
+    }
+
+    #[test]
+    fn test_sigma_level_invalid() {
+        assert!(Normal::sigma_level(0.0).is_nan());
+        assert!(Normal::sigma_level(1.0).is_nan());
+        assert!(Normal::sigma_level(-0.1).is_nan());
+        assert!(Normal::sigma_level(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_defect_rate_for_sigma() {
+        assert_in_delta(Normal::defect_rate_for_sigma(6.0) * 1e6, 3.4, 0.01);
+        for rate in [1e-9, 3.4e-6, 0.01, 0.3] {
+            let act = Normal::defect_rate_for_sigma(Normal::sigma_level(rate));
+            assert!((act - rate).abs() / rate < 0.000000001);
+        }
+        assert!(Normal::defect_rate_for_sigma(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_f32() {
+        let inputs = [-3.0, -1.0, 0.0, 1.0, 3.0];
+        for input in inputs {
+            let x = input as f32;
+            assert_eq!(
+                Normal::pdf_f32(x, 1.0, 2.0),
+                Normal::pdf(input, 1.0, 2.0) as f32
+            );
+            assert_eq!(
+                Normal::cdf_f32(x, 1.0, 2.0),
+                Normal::cdf(input, 1.0, 2.0) as f32
+            );
+        }
+        let p = 0.975_f32;
+        assert_eq!(
+            Normal::ppf_f32(p, 0.0, 1.0),
+            Normal::ppf(p as f64, 0.0, 1.0) as f32
+        );
+        assert!(Normal::ppf_f32(1.5, 0.0, 1.0).is_nan());
+        assert!(Normal::pdf_f32(0.0, 0.0, 0.0).is_nan());
+    }
+
+    #[cfg(feature = "rand-distr-compat")]
+    #[test]
+    fn test_rand_distr() {
+        use core::convert::TryFrom;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rand_distr::Distribution;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = rand_distr::Normal::try_from(Normal::new(3.0, 2.0).unwrap()).unwrap();
+        let n = 100000;
+        let mean = (0..n).map(|_| dist.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert_in_delta(mean, 3.0, 0.02);
+
+        for std_dev in [0.0, -2.0, f64::NAN] {
+            assert!(rand_distr::Normal::try_from(Normal::new_unchecked(3.0, std_dev)).is_err());
+        }
+    }
 }
@@ -0,0 +1,364 @@
+use crate::discrete::seeded_ppf;
+use crate::gamma::{ln_gamma_sign, upper_regularized};
+use crate::math::{exp, floor, log, sqrt};
+
+/// The Poisson distribution.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Poisson {
+    lambda: f64,
+}
+
+impl Poisson {
+    /// Creates a Poisson distribution with the given rate.
+    ///
+    /// Returns `None` if `lambda` is not positive and finite.
+    pub fn new(lambda: f64) -> Option<Self> {
+        if !lambda.is_finite() || lambda <= 0.0 {
+            return None;
+        }
+
+        Some(Poisson { lambda })
+    }
+
+    /// Returns the probability mass function (PMF) of the Poisson distribution.
+    pub fn pmf<T: Into<f64>>(k: T, lambda: f64) -> f64 {
+        let k = k.into();
+
+        if k.is_nan() || lambda.is_nan() || lambda <= 0.0 {
+            return f64::NAN;
+        }
+
+        if k < 0.0 || k != floor(k) || k == f64::INFINITY {
+            return 0.0;
+        }
+
+        exp(k * log(lambda) - lambda - ln_gamma_sign(k + 1.0).0)
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the Poisson distribution.
+    pub fn cdf<T: Into<f64>>(k: T, lambda: f64) -> f64 {
+        let k = k.into();
+
+        if k.is_nan() || lambda.is_nan() || lambda <= 0.0 {
+            return f64::NAN;
+        }
+
+        if k < 0.0 {
+            return 0.0;
+        }
+
+        if k == f64::INFINITY {
+            return 1.0;
+        }
+
+        upper_regularized(floor(k) + 1.0, lambda).unwrap_or(f64::NAN)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Poisson distribution.
+    ///
+    /// This is the smallest `k` where the CDF is at least `p`.
+    pub fn ppf(p: f64, lambda: f64) -> f64 {
+        if !(0.0..=1.0).contains(&p) || lambda.is_nan() || lambda <= 0.0 {
+            return f64::NAN;
+        }
+
+        if p == 0.0 {
+            return 0.0;
+        }
+
+        if p == 1.0 {
+            return f64::INFINITY;
+        }
+
+        let std_dev = sqrt(lambda);
+        seeded_ppf(p, lambda, std_dev, 1.0 / std_dev, f64::INFINITY, |k| {
+            Poisson::cdf(k, lambda)
+        })
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Poisson distribution.
+    ///
+    /// Alias for [`Poisson::ppf`].
+    pub fn quantile(p: f64, lambda: f64) -> f64 {
+        Poisson::ppf(p, lambda)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Poisson distribution.
+    ///
+    /// Alias for [`Poisson::ppf`].
+    pub fn inv_cdf(p: f64, lambda: f64) -> f64 {
+        Poisson::ppf(p, lambda)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<u64> for Poisson {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        if self.lambda < 10.0 {
+            sample_knuth(self.lambda, rng)
+        } else {
+            sample_ptrs(self.lambda, rng)
+        }
+    }
+}
+
+#[cfg(feature = "rand-distr-compat")]
+impl core::convert::TryFrom<Poisson> for rand_distr::Poisson<f64> {
+    type Error = rand_distr::PoissonError;
+
+    fn try_from(poisson: Poisson) -> Result<Self, Self::Error> {
+        rand_distr::Poisson::new(poisson.lambda)
+    }
+}
+
+// multiplies uniforms until the product drops below exp(-lambda)
+#[cfg(feature = "rand")]
+fn sample_knuth<R: rand::Rng + ?Sized>(lambda: f64, rng: &mut R) -> u64 {
+    let limit = exp(-lambda);
+    let mut k = 0;
+    let mut product = crate::sample::open_uniform(rng);
+    while product > limit {
+        k += 1;
+        product *= crate::sample::open_uniform(rng);
+    }
+    k
+}
+
+// Hörmann, W. (1993).
+// The transformed rejection method for generating Poisson random variables.
+// Insurance: Mathematics and Economics, 12(1), 39-45.
+#[cfg(feature = "rand")]
+fn sample_ptrs<R: rand::Rng + ?Sized>(lambda: f64, rng: &mut R) -> u64 {
+    use crate::math::fabs;
+    use crate::sample::open_uniform;
+
+    let ln_lambda = log(lambda);
+    let b = 0.931 + 2.53 * sqrt(lambda);
+    let a = -0.059 + 0.02483 * b;
+    let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+
+    loop {
+        let u = open_uniform(rng) - 0.5;
+        let v = open_uniform(rng);
+        let us = 0.5 - fabs(u);
+        let k = floor((2.0 * a / us + b) * u + lambda + 0.43);
+
+        // fast acceptance in the center of the hat
+        if us >= 0.07 && v <= v_r {
+            return k as u64;
+        }
+
+        if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+
+        if log(v) + log(inv_alpha) - log(a / (us * us) + b)
+            <= -lambda + k * ln_lambda - ln_gamma_sign(k + 1.0).0
+        {
+            return k as u64;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Poisson;
+
+    const INFINITY: f64 = f64::INFINITY;
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_pmf() {
+        let inputs = [-1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 2.5, INFINITY];
+        let expected = [
+            0.0, 0.13534, 0.27067, 0.27067, 0.18045, 0.09022, 0.03609, 0.01203, 0.0, 0.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Poisson::pmf(*input, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_pmf_nan() {
+        assert!(Poisson::pmf(f64::NAN, 2.0).is_nan());
+        assert!(Poisson::pmf(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_pmf_non_positive_lambda() {
+        assert!(Poisson::pmf(1.0, 0.0).is_nan());
+        assert!(Poisson::pmf(1.0, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_cdf() {
+        let inputs = [-1.0, 0.0, 1.0, 2.0, 3.0, 3.5, 4.0, 5.0, 6.0, INFINITY];
+        let expected = [
+            0.0, 0.13534, 0.40601, 0.67668, 0.85712, 0.85712, 0.94735, 0.98344, 0.99547, 1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Poisson::cdf(*input, 2.0), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_integer() {
+        assert_in_delta(Poisson::cdf(3, 2.0), 0.85712, 0.00001);
+        assert_in_delta(Poisson::pmf(3_u32, 2.0), 0.18045, 0.00001);
+    }
+
+    #[test]
+    fn test_cdf_small_lambda() {
+        let inputs = [0.0, 1.0, 2.0, 3.0];
+        let expected = [0.60653, 0.9098, 0.98561, 0.99825];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(Poisson::cdf(*input, 0.5), exp, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_nan() {
+        assert!(Poisson::cdf(f64::NAN, 2.0).is_nan());
+        assert!(Poisson::cdf(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cdf_non_positive_lambda() {
+        assert!(Poisson::cdf(1.0, 0.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf() {
+        let inputs = [0.0, 0.1, 0.13, 0.2, 0.5, 0.9, 0.99, 1.0];
+        let expected = [0.0, 0.0, 0.0, 1.0, 2.0, 4.0, 6.0, INFINITY];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_eq!(Poisson::ppf(*input, 2.0), exp);
+        }
+    }
+
+    #[test]
+    fn test_ppf_large_lambda() {
+        // matches a linear scan from zero
+        let lambda = 10000.0;
+        for p in [0.001, 0.1, 0.5, 0.9, 0.999] {
+            let mut k = 0.0;
+            while Poisson::cdf(k, lambda) < p {
+                k += 1.0;
+            }
+            assert_eq!(Poisson::ppf(p, lambda), k);
+        }
+    }
+
+    #[test]
+    fn test_ppf_round_trip() {
+        for lambda in [0.1, 2.0, 30.5, 1000.0] {
+            for p in [0.01, 0.3, 0.5, 0.7, 0.99] {
+                let k = Poisson::ppf(p, lambda);
+                assert!(Poisson::cdf(k, lambda) >= p);
+                assert!(k == 0.0 || Poisson::cdf(k - 1.0, lambda) < p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ppf_invalid() {
+        assert!(Poisson::ppf(-0.1, 2.0).is_nan());
+        assert!(Poisson::ppf(1.1, 2.0).is_nan());
+        assert!(Poisson::ppf(f64::NAN, 2.0).is_nan());
+        assert!(Poisson::ppf(0.5, 0.0).is_nan());
+        assert!(Poisson::ppf(0.5, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_aliases() {
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(Poisson::quantile(p, 2.0), Poisson::ppf(p, 2.0));
+            assert_eq!(Poisson::inv_cdf(p, 2.0), Poisson::ppf(p, 2.0));
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(Poisson::new(2.0).is_some());
+        assert!(Poisson::new(0.0).is_none());
+        assert!(Poisson::new(-1.0).is_none());
+        assert!(Poisson::new(f64::NAN).is_none());
+        assert!(Poisson::new(INFINITY).is_none());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        // Knuth and PTRS paths
+        for lambda in [0.5, 4.0, 9.9, 10.0, 50.0, 1000.0] {
+            let dist = Poisson::new(lambda).unwrap();
+            let n = 100000;
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..n {
+                let k = dist.sample(&mut rng) as f64;
+                sum += k;
+                sum_sq += k * k;
+            }
+            let mean = sum / n as f64;
+            let variance = sum_sq / n as f64 - mean * mean;
+            assert_in_delta(mean / lambda, 1.0, 0.01);
+            assert_in_delta(variance / lambda, 1.0, 0.03);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample_pmf() {
+        use rand::distributions::Distribution;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        // frequencies near the mode match the pmf for the rejection sampler
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = Poisson::new(20.0).unwrap();
+        let n = 200000;
+        let mut counts = [0; 41];
+        for _ in 0..n {
+            let k = dist.sample(&mut rng) as usize;
+            if k < counts.len() {
+                counts[k] += 1;
+            }
+        }
+        for (k, count) in counts.iter().enumerate().skip(10).take(21) {
+            assert_in_delta(
+                *count as f64 / n as f64,
+                Poisson::pmf(k as u32, 20.0),
+                0.003,
+            );
+        }
+    }
+
+    #[cfg(feature = "rand-distr-compat")]
+    #[test]
+    fn test_rand_distr() {
+        use core::convert::TryFrom;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rand_distr::Distribution;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = rand_distr::Poisson::try_from(Poisson::new(4.5).unwrap()).unwrap();
+        let n = 100000;
+        let mean = (0..n).map(|_| dist.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert_in_delta(mean, 4.5, 0.05);
+    }
+}
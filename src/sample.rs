@@ -0,0 +1,106 @@
+use crate::math::{floor, log, pow, sqrt};
+use rand::Rng;
+
+// returns a uniform sample in the open interval (0, 1)
+pub(crate) fn open_uniform<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    loop {
+        let u: f64 = rng.gen();
+        if u > 0.0 {
+            return u;
+        }
+    }
+}
+
+// Marsaglia, G., & Bray, T. A. (1964).
+// A convenient method for generating normal variables.
+// SIAM Review, 6(3), 260-264.
+pub(crate) fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    loop {
+        let u = 2.0 * rng.gen::<f64>() - 1.0;
+        let v = 2.0 * rng.gen::<f64>() - 1.0;
+        let s = u * u + v * v;
+        if s > 0.0 && s < 1.0 {
+            return u * sqrt(-2.0 * log(s) / s);
+        }
+    }
+}
+
+// Marsaglia, G., & Tsang, W. W. (2000).
+// A simple method for generating gamma variables.
+// ACM Transactions on Mathematical Software, 26(3), 363-372.
+pub(crate) fn standard_gamma<R: Rng + ?Sized>(shape: f64, rng: &mut R) -> f64 {
+    // boost shape above one and scale back with a uniform power
+    if shape < 1.0 {
+        let u = open_uniform(rng);
+        return standard_gamma(shape + 1.0, rng) * pow(u, 1.0 / shape);
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / sqrt(9.0 * d);
+    loop {
+        let x = standard_normal(rng);
+        let t = 1.0 + c * x;
+        if t <= 0.0 {
+            continue;
+        }
+
+        let v = t * t * t;
+        let u = open_uniform(rng);
+
+        // squeeze avoids the logarithms most of the time
+        let x2 = x * x;
+        if u < 1.0 - 0.0331 * x2 * x2 {
+            return d * v;
+        }
+
+        if log(u) < 0.5 * x2 + d * (1.0 - v + log(v)) {
+            return d * v;
+        }
+    }
+}
+
+pub(crate) fn chi_squared<R: Rng + ?Sized>(k: f64, rng: &mut R) -> f64 {
+    // sum of squared normals is cheaper for small integer degrees of freedom
+    if k <= 10.0 && k == floor(k) {
+        let mut sum = 0.0;
+        for _ in 0..k as u32 {
+            let z = standard_normal(rng);
+            sum += z * z;
+        }
+        return sum;
+    }
+
+    2.0 * standard_gamma(0.5 * k, rng)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{open_uniform, standard_normal};
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_open_uniform() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        for _ in 0..10000 {
+            let u = open_uniform(&mut rng);
+            assert!(u > 0.0 && u < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_standard_normal() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let n = 100000;
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        for _ in 0..n {
+            let x = standard_normal(&mut rng);
+            sum += x;
+            sum_sq += x * x;
+        }
+        let mean = sum / n as f64;
+        assert!(mean.abs() < 0.02, "{}", mean);
+        assert!((sum_sq / n as f64 - mean * mean - 1.0).abs() < 0.02);
+    }
+}
@@ -0,0 +1,1261 @@
+//! Statistical utilities built on the distributions.
+
+use crate::math::{exp, fabs, log, sqrt};
+use crate::{ContinuousDistribution, Normal, StudentsT};
+use core::f64::consts::{PI, SQRT_2};
+
+/// Fits a probit calibration model mapping scores to probabilities.
+///
+/// Returns the normal distribution whose CDF maps a score to a calibrated probability, found with Fisher scoring on the probit log-likelihood. Returns `None` if `scores` and `labels` have different lengths, the fit does not converge, or scores are not positively associated with labels.
+pub fn probit_calibrate(scores: &[f64], labels: &[bool]) -> Option<Normal> {
+    if scores.len() != labels.len() {
+        return None;
+    }
+
+    // probability is cdf(intercept + slope * score)
+    let mut intercept = 0.0;
+    let mut slope = 1.0;
+
+    for _ in 0..100 {
+        let mut g0 = 0.0;
+        let mut g1 = 0.0;
+        let mut h00 = 0.0;
+        let mut h01 = 0.0;
+        let mut h11 = 0.0;
+
+        for (s, y) in scores.iter().zip(labels) {
+            let eta = intercept + slope * s;
+            let p = Normal::std_cdf(eta).clamp(1e-15, 1.0 - 1e-15);
+            let d = Normal::pdf(eta, 0.0, 1.0);
+            let v = p * (1.0 - p);
+
+            let r = if *y { 1.0 - p } else { -p } * d / v;
+            g0 += r;
+            g1 += r * s;
+
+            let w = d * d / v;
+            h00 += w;
+            h01 += w * s;
+            h11 += w * s * s;
+        }
+
+        let det = h00 * h11 - h01 * h01;
+        if det == 0.0 || det.is_nan() {
+            return None;
+        }
+
+        let step0 = (h11 * g0 - h01 * g1) / det;
+        let step1 = (h00 * g1 - h01 * g0) / det;
+        intercept += step0;
+        slope += step1;
+
+        if fabs(step0) < 1e-10 && fabs(step1) < 1e-10 {
+            if slope <= 0.0 {
+                return None;
+            }
+            return Normal::new(-intercept / slope, 1.0 / slope).ok();
+        }
+    }
+
+    None
+}
+
+/// A link function for generalized linear models.
+pub trait Link {
+    /// Maps the mean to the linear predictor.
+    fn link(&self, mu: f64) -> f64;
+
+    /// Maps the linear predictor to the mean.
+    fn link_inv(&self, eta: f64) -> f64;
+
+    /// Returns the derivative of the inverse link with respect to the linear predictor.
+    fn link_inv_deriv(&self, eta: f64) -> f64;
+}
+
+/// The probit link, based on the standard normal distribution.
+pub struct Probit;
+
+impl Link for Probit {
+    fn link(&self, mu: f64) -> f64 {
+        probit_link(mu)
+    }
+
+    fn link_inv(&self, eta: f64) -> f64 {
+        probit_link_inv(eta)
+    }
+
+    fn link_inv_deriv(&self, eta: f64) -> f64 {
+        probit_link_inv_deriv(eta)
+    }
+}
+
+/// Returns the probit link, the standard normal PPF of `mu`.
+///
+/// This is the same as [`crate::probit`]. Returns `NAN` if `mu` is outside `(0, 1)`.
+pub fn probit_link(mu: f64) -> f64 {
+    crate::probit(mu)
+}
+
+/// Returns the inverse probit link, the standard normal CDF of `eta`.
+pub fn probit_link_inv(eta: f64) -> f64 {
+    Normal::std_cdf(eta)
+}
+
+/// Returns the derivative of the inverse probit link, the standard normal PDF of `eta`.
+pub fn probit_link_inv_deriv(eta: f64) -> f64 {
+    Normal::pdf(eta, 0.0, 1.0)
+}
+
+/// A distribution family that can be fit with [`fit_by_moments`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Family {
+    /// The normal distribution.
+    Normal,
+    /// The exponential distribution.
+    Exponential,
+    /// The gamma distribution.
+    Gamma,
+    /// The beta distribution.
+    Beta,
+}
+
+/// Parameters returned by [`fit_by_moments`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Params {
+    /// Parameters of the normal distribution.
+    Normal { mean: f64, std_dev: f64 },
+    /// Parameters of the exponential distribution.
+    Exponential { rate: f64 },
+    /// Parameters of the gamma distribution.
+    Gamma { shape: f64, scale: f64 },
+    /// Parameters of the beta distribution.
+    Beta { alpha: f64, beta: f64 },
+}
+
+/// Fits a distribution to samples by matching the sample mean and variance.
+///
+/// The variance is the population variance of the samples. Returns `NAN` parameters if the samples are empty or the moments are infeasible for the family, like negative data for a gamma distribution.
+pub fn fit_by_moments(family: Family, samples: &[f64]) -> Params {
+    let (mean, variance) = moments(samples);
+    let undefined = mean.is_nan() || variance.is_nan();
+
+    match family {
+        Family::Normal => {
+            if undefined {
+                return Params::Normal {
+                    mean: f64::NAN,
+                    std_dev: f64::NAN,
+                };
+            }
+            Params::Normal {
+                mean,
+                std_dev: sqrt(variance),
+            }
+        }
+        Family::Exponential => {
+            if undefined || samples.iter().any(|&x| x < 0.0) || mean <= 0.0 {
+                return Params::Exponential { rate: f64::NAN };
+            }
+            Params::Exponential { rate: 1.0 / mean }
+        }
+        Family::Gamma => {
+            if undefined || samples.iter().any(|&x| x < 0.0) || mean <= 0.0 || variance <= 0.0 {
+                return Params::Gamma {
+                    shape: f64::NAN,
+                    scale: f64::NAN,
+                };
+            }
+            Params::Gamma {
+                shape: mean * mean / variance,
+                scale: variance / mean,
+            }
+        }
+        Family::Beta => {
+            // variance must be less than mean * (1 - mean)
+            if undefined
+                || samples.iter().any(|x| !(0.0..=1.0).contains(x))
+                || variance <= 0.0
+                || variance >= mean * (1.0 - mean)
+            {
+                return Params::Beta {
+                    alpha: f64::NAN,
+                    beta: f64::NAN,
+                };
+            }
+            let c = mean * (1.0 - mean) / variance - 1.0;
+            Params::Beta {
+                alpha: mean * c,
+                beta: (1.0 - mean) * c,
+            }
+        }
+    }
+}
+
+/// Returns the log-likelihood of `data` under a distribution, the sum of `ln_pdf` over the data.
+pub fn log_likelihood<D: ContinuousDistribution>(d: &D, data: &[f64]) -> f64 {
+    data.iter().map(|&x| d.ln_pdf(x)).sum()
+}
+
+/// Returns the Akaike information criterion (AIC) of a distribution fit to `data` with `k_params` estimated parameters.
+///
+/// This is `2 * k_params - 2 * log_likelihood`, so lower is better. Returns `NAN` if `data` is empty.
+pub fn aic<D: ContinuousDistribution>(d: &D, data: &[f64], k_params: usize) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+
+    2.0 * k_params as f64 - 2.0 * log_likelihood(d, data)
+}
+
+/// Returns the Bayesian information criterion (BIC) of a distribution fit to `data` with `k_params` estimated parameters.
+///
+/// This is `k_params * ln(n) - 2 * log_likelihood`, so lower is better. Returns `NAN` if `data` is empty.
+pub fn bic<D: ContinuousDistribution>(d: &D, data: &[f64], k_params: usize) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+
+    k_params as f64 * log(data.len() as f64) - 2.0 * log_likelihood(d, data)
+}
+
+/// Approximates an unnormalized log-density with a normal distribution around its mode.
+///
+/// Returns the mean and standard deviation of the normal distribution, with the variance set from the curvature at `mode`, estimated by central finite differences with step `h`. Returns `NAN` values if `h` is not positive or the log-density is not concave at `mode`.
+pub fn laplace_approximation(log_density: impl Fn(f64) -> f64, mode: f64, h: f64) -> (f64, f64) {
+    if mode.is_nan() || h.is_nan() || h <= 0.0 {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let curvature =
+        (log_density(mode + h) - 2.0 * log_density(mode) + log_density(mode - h)) / (h * h);
+    if curvature.is_nan() || curvature >= 0.0 {
+        return (f64::NAN, f64::NAN);
+    }
+
+    (mode, sqrt(-1.0 / curvature))
+}
+
+/// Returns the expectation of `f(X)` for `X` normally distributed, computed with Gauss-Hermite quadrature.
+///
+/// Supports 5, 10, and 20 point rules. Returns `NAN` for other values of `n_points` or if `std_dev` is not positive.
+#[allow(clippy::excessive_precision)]
+pub fn gauss_hermite(f: impl Fn(f64) -> f64, mean: f64, std_dev: f64, n_points: usize) -> f64 {
+    if mean.is_nan() || std_dev.is_nan() || std_dev <= 0.0 {
+        return f64::NAN;
+    }
+
+    // nonnegative nodes and weights for the weight function exp(-x^2)
+    let rule: &[(f64, f64)] = match n_points {
+        5 => &[
+            (0.0, 0.94530872048294188),
+            (0.95857246461381851, 0.39361932315224116),
+            (2.0201828704560856, 0.019953242059045913),
+        ],
+        10 => &[
+            (0.34290132722370461, 0.6108626337353258),
+            (1.0366108297895137, 0.24013861108231469),
+            (1.7566836492998818, 0.033874394455481063),
+            (2.5327316742327898, 0.0013436457467812327),
+            (3.4361591188377376, 7.6404328552326206e-6),
+        ],
+        20 => &[
+            (0.24534070830090125, 0.46224366960061009),
+            (0.73747372854539436, 0.28667550536283413),
+            (1.234076215395323, 0.10901720602002332),
+            (1.7385377121165862, 0.024810520887463611),
+            (2.2549740020892755, 0.0032437733422378618),
+            (2.7888060584281305, 0.00022833863601635397),
+            (3.3478545673832163, 7.8025564785320637e-6),
+            (3.9447640401156252, 1.0860693707692817e-7),
+            (4.6036824495507443, 4.3993409922731806e-10),
+            (5.3874808900112329, 2.2293936455341513e-13),
+        ],
+        _ => return f64::NAN,
+    };
+
+    let scale = SQRT_2 * std_dev;
+    let mut sum = 0.0;
+    for &(x, w) in rule {
+        sum += if x == 0.0 {
+            w * f(mean)
+        } else {
+            w * (f(mean - scale * x) + f(mean + scale * x))
+        };
+    }
+    sum / sqrt(PI)
+}
+
+/// Returns the round-trip error `|cdf(ppf(p)) - p|` of a distribution for each value in `probs`, writing the results to `out`.
+///
+/// # Panics
+///
+/// Panics if `probs` and `out` have different lengths.
+pub fn quantile_accuracy(dist: &impl ContinuousDistribution, probs: &[f64], out: &mut [f64]) {
+    assert_eq!(
+        probs.len(),
+        out.len(),
+        "probs and out must have the same length"
+    );
+
+    for (p, o) in probs.iter().zip(out.iter_mut()) {
+        *o = fabs(dist.cdf(dist.ppf(*p)) - p);
+    }
+}
+
+/// Returns the Black-Scholes price of a European call option.
+///
+/// `rate` is the continuously compounded risk-free rate and `vol` is the annualized volatility, with `time` to expiration in years. Returns `NAN` if `spot`, `strike`, `vol`, or `time` is not positive.
+pub fn black_scholes_call(spot: f64, strike: f64, rate: f64, vol: f64, time: f64) -> f64 {
+    let (d1, d2) = black_scholes_d(spot, strike, rate, vol, time);
+    if d1.is_nan() {
+        return f64::NAN;
+    }
+
+    // survival function keeps deep out-of-the-money prices accurate
+    spot * Normal::sf(-d1, 0.0, 1.0) - strike * exp(-rate * time) * Normal::sf(-d2, 0.0, 1.0)
+}
+
+/// Returns the Black-Scholes price of a European put option.
+///
+/// Takes the same arguments as [`black_scholes_call`]. Returns `NAN` if `spot`, `strike`, `vol`, or `time` is not positive.
+pub fn black_scholes_put(spot: f64, strike: f64, rate: f64, vol: f64, time: f64) -> f64 {
+    let (d1, d2) = black_scholes_d(spot, strike, rate, vol, time);
+    if d1.is_nan() {
+        return f64::NAN;
+    }
+
+    strike * exp(-rate * time) * Normal::sf(d2, 0.0, 1.0) - spot * Normal::sf(d1, 0.0, 1.0)
+}
+
+/// Returns the Black-Scholes delta of a European call option, the derivative of the price with respect to `spot`.
+///
+/// The delta of a put is this value minus one. Returns `NAN` if `spot`, `strike`, `vol`, or `time` is not positive.
+pub fn bs_delta(spot: f64, strike: f64, rate: f64, vol: f64, time: f64) -> f64 {
+    let (d1, _) = black_scholes_d(spot, strike, rate, vol, time);
+    if d1.is_nan() {
+        return f64::NAN;
+    }
+
+    Normal::std_cdf(d1)
+}
+
+/// Returns the Black-Scholes gamma of a European option, the second derivative of the price with respect to `spot`.
+///
+/// Calls and puts have the same gamma. Returns `NAN` if `spot`, `strike`, `vol`, or `time` is not positive.
+pub fn bs_gamma(spot: f64, strike: f64, rate: f64, vol: f64, time: f64) -> f64 {
+    let (d1, _) = black_scholes_d(spot, strike, rate, vol, time);
+    if d1.is_nan() {
+        return f64::NAN;
+    }
+
+    Normal::pdf(d1, 0.0, 1.0) / (spot * vol * sqrt(time))
+}
+
+/// Returns the Black-Scholes vega of a European option, the derivative of the price with respect to `vol`.
+///
+/// Calls and puts have the same vega. Returns `NAN` if `spot`, `strike`, `vol`, or `time` is not positive.
+pub fn bs_vega(spot: f64, strike: f64, rate: f64, vol: f64, time: f64) -> f64 {
+    let (d1, _) = black_scholes_d(spot, strike, rate, vol, time);
+    if d1.is_nan() {
+        return f64::NAN;
+    }
+
+    spot * Normal::pdf(d1, 0.0, 1.0) * sqrt(time)
+}
+
+/// Returns the volatility at which the Black-Scholes price of a European option equals `price`, found with Newton's method.
+///
+/// Returns `NAN` if `spot`, `strike`, or `time` is not positive, if `price` is outside the no-arbitrage bounds, or if the iteration does not converge.
+pub fn implied_volatility(
+    price: f64,
+    spot: f64,
+    strike: f64,
+    rate: f64,
+    time: f64,
+    is_call: bool,
+) -> f64 {
+    if price.is_nan() || spot.is_nan() || strike.is_nan() || rate.is_nan() || time.is_nan() {
+        return f64::NAN;
+    }
+
+    if spot <= 0.0 || strike <= 0.0 || time <= 0.0 {
+        return f64::NAN;
+    }
+
+    // price must be strictly between the values at zero and infinite volatility
+    let discounted_strike = strike * exp(-rate * time);
+    let (lower, upper) = if is_call {
+        ((spot - discounted_strike).max(0.0), spot)
+    } else {
+        ((discounted_strike - spot).max(0.0), discounted_strike)
+    };
+    if price <= lower || price >= upper {
+        return f64::NAN;
+    }
+
+    // Manaster, S., & Koehler, G. (1982).
+    // The calculation of implied variances from the Black-Scholes model: A note.
+    // The Journal of Finance, 37(1), 227-230.
+    let mut vol = sqrt(2.0 * fabs(log(spot / strike) + rate * time) / time);
+    if vol == 0.0 {
+        // Brenner-Subrahmanyam approximation for at-the-money options
+        vol = sqrt(2.0 * PI / time) * price / spot;
+    }
+
+    for _ in 0..100 {
+        let model = if is_call {
+            black_scholes_call(spot, strike, rate, vol, time)
+        } else {
+            black_scholes_put(spot, strike, rate, vol, time)
+        };
+        let diff = model - price;
+        if diff == 0.0 {
+            return vol;
+        }
+
+        let step = diff / bs_vega(spot, strike, rate, vol, time);
+        vol -= step;
+
+        if !vol.is_finite() || vol <= 0.0 {
+            return f64::NAN;
+        }
+
+        if fabs(step) <= 1e-10 * vol {
+            return vol;
+        }
+    }
+
+    f64::NAN
+}
+
+/// Returns the standard error and confidence interval half-width of the mean response at `x0` for a simple linear regression.
+///
+/// `x_mean` and `sxx` are the mean and sum of squared deviations of the predictor, `n` is the number of observations, and `residual_std` is the residual standard error. The interval for the fitted line at `x0` is the fitted value plus or minus the half-width. Returns `NAN` values if `n` is not greater than 2, `sxx` or `residual_std` is not positive, or `confidence` is not between 0 and 1.
+pub fn regression_band(
+    x0: f64,
+    x_mean: f64,
+    sxx: f64,
+    n: usize,
+    residual_std: f64,
+    confidence: f64,
+) -> (f64, f64) {
+    if x0.is_nan() || x_mean.is_nan() || sxx.is_nan() || residual_std.is_nan() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    if n <= 2 || sxx <= 0.0 || residual_std <= 0.0 || !(confidence > 0.0 && confidence < 1.0) {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let nf = n as f64;
+    let dx = x0 - x_mean;
+    let std_err = residual_std * sqrt(1.0 / nf + dx * dx / sxx);
+    let t = StudentsT::ppf(0.5 + 0.5 * confidence, nf - 2.0);
+    (std_err, t * std_err)
+}
+
+/// Returns the cumulative distribution function (CDF) at `x` by integrating `pdf` from `lower`, using adaptive Gauss-Kronrod quadrature.
+///
+/// `lower` and `x` may be infinite. Returns `NAN` if `lower` or `x` is `NAN` or `lower` is positive infinity.
+pub fn cdf_via_quadrature<F: Fn(f64) -> f64>(pdf: F, lower: f64, x: f64) -> f64 {
+    if lower.is_nan() || x.is_nan() || lower == f64::INFINITY {
+        return f64::NAN;
+    }
+
+    if x <= lower {
+        return 0.0;
+    }
+
+    integrate(pdf, lower, x).clamp(0.0, 1.0)
+}
+
+/// Returns the percent-point/quantile function (PPF) at `p` by bisection on `cdf`, starting from the bracket `[lo, hi]`.
+///
+/// The bracket is expanded as needed, so it only needs to be a starting guess for distributions with unbounded support. Returns the smallest `x` found with `cdf(x) >= p`, or with `cdf(x) > 0` when `p` is 0, so `p` of 0 and 1 give the ends of a bounded support, up to rounding in `cdf`. Returns `NAN` if `p` is outside `[0, 1]` or the bracket is invalid.
+pub fn ppf_via_bisection<F: Fn(f64) -> f64>(cdf: F, p: f64, lo: f64, hi: f64) -> f64 {
+    if !(0.0..=1.0).contains(&p) || !lo.is_finite() || !hi.is_finite() || lo > hi {
+        return f64::NAN;
+    }
+
+    // cdf(x) >= 0 everywhere, so search for the start of the support when p is 0
+    let below = |x: f64| if p == 0.0 { cdf(x) <= 0.0 } else { cdf(x) < p };
+
+    // expand the bracket geometrically until it contains the quantile
+    let mut lo = lo;
+    let mut hi = hi;
+    let mut width = (hi - lo).max(1.0);
+    while !below(lo) {
+        lo -= width;
+        width *= 2.0;
+        if !lo.is_finite() {
+            return f64::NEG_INFINITY;
+        }
+    }
+
+    let mut width = (hi - lo).max(1.0);
+    while below(hi) {
+        hi += width;
+        width *= 2.0;
+        if !hi.is_finite() {
+            return f64::INFINITY;
+        }
+    }
+
+    // below(lo) and !below(hi) hold throughout
+    for _ in 0..2100 {
+        let mid = lo + 0.5 * (hi - lo);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+
+        if below(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    hi
+}
+
+const QUADRATURE_DEPTH: u32 = 20;
+
+// integrates f from a to b, where a < b and either limit may be infinite
+pub(crate) fn integrate<F: Fn(f64) -> f64>(f: F, a: f64, b: f64) -> f64 {
+    // map infinite limits onto finite intervals
+    match (a.is_finite(), b.is_finite()) {
+        (true, true) => gauss_kronrod(&f, a, b, QUADRATURE_DEPTH),
+        (false, true) => {
+            let g = |t: f64| f(b - (1.0 - t) / t) / (t * t);
+            gauss_kronrod(&g, 0.0, 1.0, QUADRATURE_DEPTH)
+        }
+        (true, false) => {
+            let g = |t: f64| f(a + t / (1.0 - t)) / ((1.0 - t) * (1.0 - t));
+            gauss_kronrod(&g, 0.0, 1.0, QUADRATURE_DEPTH)
+        }
+        (false, false) => {
+            let g =
+                |t: f64| (f(t / (1.0 - t * t)) * (1.0 + t * t)) / ((1.0 - t * t) * (1.0 - t * t));
+            gauss_kronrod(&g, -1.0, 1.0, QUADRATURE_DEPTH)
+        }
+    }
+}
+
+// adaptive 15-point Gauss-Kronrod rule, bisecting until the embedded 7-point Gauss rule agrees
+#[allow(clippy::excessive_precision)]
+fn gauss_kronrod<F: Fn(f64) -> f64>(f: &F, a: f64, b: f64, depth: u32) -> f64 {
+    const KRONROD_NODES: [f64; 8] = [
+        0.991455371120812639206854697526329,
+        0.949107912342758524526189684047851,
+        0.864864423359769072789712788640926,
+        0.741531185599394439863864773280788,
+        0.586087235467691130294144845693013,
+        0.405845151377397166906606412076961,
+        0.207784955007898467600689403773245,
+        0.0,
+    ];
+    const KRONROD_WEIGHTS: [f64; 8] = [
+        0.022935322010529224963732008058970,
+        0.063092092629978553290700663189204,
+        0.104790010322250183839876322541518,
+        0.140653259715525918745189590510238,
+        0.169004726639267902826583426598550,
+        0.190350578064785409913256402421014,
+        0.204432940075298892414161999234649,
+        0.209482141084727828012999174891714,
+    ];
+    // weights of the Gauss rule at the odd Kronrod nodes
+    const GAUSS_WEIGHTS: [f64; 4] = [
+        0.129484966168869693270611432679082,
+        0.279705391489276667901467771423780,
+        0.381830050505118944950369775488975,
+        0.417959183673469387755102040816327,
+    ];
+
+    let center = 0.5 * (a + b);
+    let half = 0.5 * (b - a);
+    let mut kronrod = 0.0;
+    let mut gauss = 0.0;
+    for (i, (x, w)) in KRONROD_NODES.iter().zip(KRONROD_WEIGHTS).enumerate() {
+        let y = if *x == 0.0 {
+            f(center)
+        } else {
+            f(center - half * x) + f(center + half * x)
+        };
+        kronrod += w * y;
+        if i % 2 == 1 {
+            gauss += GAUSS_WEIGHTS[i / 2] * y;
+        }
+    }
+    kronrod *= half;
+    gauss *= half;
+
+    if depth == 0 || fabs(kronrod - gauss) <= 1e-12 {
+        return kronrod;
+    }
+
+    gauss_kronrod(f, a, center, depth - 1) + gauss_kronrod(f, center, b, depth - 1)
+}
+
+// returns d1 and d2 for the Black-Scholes formulas, or NAN values for invalid inputs
+fn black_scholes_d(spot: f64, strike: f64, rate: f64, vol: f64, time: f64) -> (f64, f64) {
+    if spot.is_nan() || strike.is_nan() || rate.is_nan() || vol.is_nan() || time.is_nan() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    if spot <= 0.0 || strike <= 0.0 || vol <= 0.0 || time <= 0.0 {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let vol_time = vol * sqrt(time);
+    let d1 = (log(spot / strike) + (rate + 0.5 * vol * vol) * time) / vol_time;
+    (d1, d1 - vol_time)
+}
+
+// returns the mean and population variance
+fn moments(samples: &[f64]) -> (f64, f64) {
+    if samples.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+    (mean, variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        aic, bic, black_scholes_call, black_scholes_put, bs_delta, bs_gamma, bs_vega,
+        cdf_via_quadrature, fit_by_moments, gauss_hermite, implied_volatility,
+        laplace_approximation, log_likelihood, ppf_via_bisection, probit_calibrate, probit_link,
+        probit_link_inv, probit_link_inv_deriv, quantile_accuracy, regression_band, Family, Link,
+        Params, Probit,
+    };
+    use crate::math::{exp, log, sqrt};
+    use crate::{Beta, Cauchy, ContinuousDistribution, FitMoments, Normal, StudentsT};
+
+    fn assert_in_delta(act: f64, exp: f64, delta: f64) {
+        if exp.is_finite() {
+            assert!((exp - act).abs() < delta, "{} != {}", act, exp);
+        } else {
+            assert_eq!(act, exp);
+        }
+    }
+
+    #[test]
+    fn test_probit_calibrate() {
+        // labels with frequencies following a known probit model
+        let mut scores = [0.0; 61 * 200];
+        let mut labels = [false; 61 * 200];
+        for i in 0..61 {
+            let s = -3.0 + 0.1 * i as f64;
+            let positives = (Normal::cdf(s, 0.5, 1.5) * 200.0).round() as usize;
+            for j in 0..200 {
+                scores[i * 200 + j] = s;
+                labels[i * 200 + j] = j < positives;
+            }
+        }
+
+        let normal = probit_calibrate(&scores, &labels).unwrap();
+        let mean = normal.ppf(0.5);
+        assert_in_delta(mean, 0.5, 0.01);
+        assert_in_delta(normal.ppf(Normal::std_cdf(1.0)) - mean, 1.5, 0.01);
+
+        // held-out scores
+        for s in [-2.75, -1.25, 0.05, 0.35, 1.65, 2.95] {
+            assert_in_delta(normal.cdf(s), Normal::cdf(s, 0.5, 1.5), 0.005);
+        }
+    }
+
+    #[test]
+    fn test_probit_calibrate_negative_association() {
+        let scores = [-2.0, -1.0, -0.5, 0.5, 1.0, 2.0];
+        let labels = [true, true, false, true, false, false];
+        assert!(probit_calibrate(&scores, &labels).is_none());
+    }
+
+    #[test]
+    fn test_probit_calibrate_different_lengths() {
+        assert!(probit_calibrate(&[0.0, 1.0], &[true]).is_none());
+    }
+
+    #[test]
+    fn test_probit_link() {
+        assert_in_delta(probit_link(0.5), 0.0, 0.00001);
+        assert_in_delta(probit_link(0.975), 1.95996, 0.00001);
+        assert_in_delta(probit_link(0.1), -1.28155, 0.00001);
+    }
+
+    #[test]
+    fn test_probit_link_invalid() {
+        assert!(probit_link(0.0).is_nan());
+        assert!(probit_link(1.0).is_nan());
+        assert!(probit_link(-0.5).is_nan());
+        assert!(probit_link(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_probit_link_matches_probit() {
+        for mu in [1e-300, 0.001, 0.1, 0.5, 0.9, 0.999, 1.0 - 1e-16] {
+            assert_eq!(probit_link(mu), crate::probit(mu));
+        }
+    }
+
+    #[test]
+    fn test_probit_link_round_trip() {
+        for mu in [0.001, 0.1, 0.3, 0.5, 0.7, 0.9, 0.999] {
+            assert_in_delta(probit_link_inv(probit_link(mu)), mu, 0.000000000001);
+        }
+        for eta in [-3.0, -1.0, 0.0, 0.5, 2.0] {
+            assert_in_delta(probit_link(probit_link_inv(eta)), eta, 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_probit_link_inv_deriv() {
+        let h = 1e-6;
+        for eta in [-2.0, -0.5, 0.0, 1.0, 3.0] {
+            let numeric = (probit_link_inv(eta + h) - probit_link_inv(eta - h)) / (2.0 * h);
+            assert_in_delta(probit_link_inv_deriv(eta), numeric, 0.0000001);
+        }
+    }
+
+    #[test]
+    fn test_probit() {
+        assert_eq!(Probit.link(0.3), probit_link(0.3));
+        assert_eq!(Probit.link_inv(0.3), probit_link_inv(0.3));
+        assert_eq!(Probit.link_inv_deriv(0.3), probit_link_inv_deriv(0.3));
+    }
+
+    #[test]
+    fn test_fit_by_moments_normal() {
+        let mut samples = [0.0; 1000];
+        for (i, x) in samples.iter_mut().enumerate() {
+            *x = Normal::ppf((i as f64 + 0.5) / 1000.0, 3.0, 2.0);
+        }
+        match fit_by_moments(Family::Normal, &samples) {
+            Params::Normal { mean, std_dev } => {
+                assert_in_delta(mean, 3.0, 0.000001);
+                assert_in_delta(std_dev, 2.0, 0.01);
+            }
+            params => panic!("unexpected {:?}", params),
+        }
+    }
+
+    #[test]
+    fn test_fit_by_moments_exponential() {
+        let mut samples = [0.0; 1000];
+        for (i, x) in samples.iter_mut().enumerate() {
+            *x = -log(1.0 - (i as f64 + 0.5) / 1000.0) / 0.5;
+        }
+        match fit_by_moments(Family::Exponential, &samples) {
+            Params::Exponential { rate } => assert_in_delta(rate, 0.5, 0.001),
+            params => panic!("unexpected {:?}", params),
+        }
+    }
+
+    #[test]
+    fn test_fit_by_moments_gamma() {
+        // mean 6 and variance 13.6
+        let samples = [1.0, 3.0, 6.0, 9.0, 11.0];
+        let (shape, scale) = match fit_by_moments(Family::Gamma, &samples) {
+            Params::Gamma { shape, scale } => (shape, scale),
+            params => panic!("unexpected {:?}", params),
+        };
+        assert_in_delta(shape * scale, 6.0, 0.000001);
+        assert_in_delta(shape * scale * scale, 13.6, 0.000001);
+    }
+
+    #[test]
+    fn test_fit_by_moments_beta() {
+        // alpha 2 and beta 3 have mean 0.4 and variance 0.04
+        let samples = [0.2, 0.6];
+        match fit_by_moments(Family::Beta, &samples) {
+            Params::Beta { alpha, beta } => {
+                assert_in_delta(alpha, 2.0, 0.000001);
+                assert_in_delta(beta, 3.0, 0.000001);
+            }
+            params => panic!("unexpected {:?}", params),
+        }
+    }
+
+    #[test]
+    fn test_log_likelihood() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let data = [-1.0, 0.0, 2.0];
+        assert_in_delta(
+            log_likelihood(&normal, &data),
+            -5.256815599614018,
+            0.000000000001,
+        );
+        assert_eq!(log_likelihood(&normal, &[]), 0.0);
+    }
+
+    #[test]
+    fn test_aic_bic() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let data = [-1.0, 0.0, 2.0];
+        assert_in_delta(aic(&normal, &data, 2), 14.513631199228036, 0.000000000001);
+        assert_in_delta(bic(&normal, &data, 2), 12.710855776564255, 0.000000000001);
+    }
+
+    #[test]
+    fn test_aic_bic_heavy_tails() {
+        let t = StudentsT::new(3).unwrap();
+        let mut data = [0.0; 200];
+        for (i, x) in data.iter_mut().enumerate() {
+            *x = t.ppf((i as f64 + 0.5) / 200.0);
+        }
+        let normal = Normal::fit_moments(&data).unwrap();
+        assert!(aic(&t, &data, 1) < aic(&normal, &data, 2));
+        assert!(bic(&t, &data, 1) < bic(&normal, &data, 2));
+    }
+
+    #[test]
+    fn test_aic_bic_empty() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        assert!(aic(&normal, &[], 2).is_nan());
+        assert!(bic(&normal, &[], 2).is_nan());
+    }
+
+    #[test]
+    fn test_fit_by_moments_infeasible() {
+        let nan_gamma = fit_by_moments(Family::Gamma, &[-1.0, 2.0, 3.0]);
+        assert!(
+            matches!(nan_gamma, Params::Gamma { shape, scale } if shape.is_nan() && scale.is_nan())
+        );
+
+        let nan_exponential = fit_by_moments(Family::Exponential, &[-1.0, 2.0]);
+        assert!(matches!(nan_exponential, Params::Exponential { rate } if rate.is_nan()));
+
+        let nan_beta = fit_by_moments(Family::Beta, &[0.5, 1.5]);
+        assert!(
+            matches!(nan_beta, Params::Beta { alpha, beta } if alpha.is_nan() && beta.is_nan())
+        );
+
+        // variance too large for a beta distribution
+        let nan_beta = fit_by_moments(Family::Beta, &[0.0, 1.0]);
+        assert!(matches!(nan_beta, Params::Beta { alpha, .. } if alpha.is_nan()));
+
+        let nan_normal = fit_by_moments(Family::Normal, &[]);
+        assert!(matches!(nan_normal, Params::Normal { mean, .. } if mean.is_nan()));
+    }
+
+    #[test]
+    fn test_laplace_approximation() {
+        let (mean, std_dev) = laplace_approximation(|x| log(Normal::pdf(x, 1.5, 0.7)), 1.5, 0.001);
+        assert_in_delta(mean, 1.5, 0.000001);
+        assert_in_delta(std_dev, 0.7, 0.000001);
+
+        // normalizing constant does not matter
+        let (mean, std_dev) =
+            laplace_approximation(|x| -0.5 * (x + 2.0) * (x + 2.0) / 9.0 + 5.0, -2.0, 0.01);
+        assert_in_delta(mean, -2.0, 0.000001);
+        assert_in_delta(std_dev, 3.0, 0.000001);
+    }
+
+    #[test]
+    fn test_laplace_approximation_invalid() {
+        let (mean, std_dev) = laplace_approximation(|x| x * x, 0.0, 0.001);
+        assert!(mean.is_nan());
+        assert!(std_dev.is_nan());
+
+        let (mean, std_dev) = laplace_approximation(|x| -x * x, 0.0, 0.0);
+        assert!(mean.is_nan());
+        assert!(std_dev.is_nan());
+    }
+
+    #[test]
+    fn test_gauss_hermite() {
+        for n_points in [5, 10, 20] {
+            assert_in_delta(
+                gauss_hermite(|_| 1.0, 1.5, 2.0, n_points),
+                1.0,
+                0.000000000001,
+            );
+            assert_in_delta(
+                gauss_hermite(|x| x, 1.5, 2.0, n_points),
+                1.5,
+                0.000000000001,
+            );
+            assert_in_delta(
+                gauss_hermite(|x| x * x, 1.5, 2.0, n_points),
+                1.5 * 1.5 + 2.0 * 2.0,
+                0.000000000001,
+            );
+            assert_in_delta(
+                gauss_hermite(|x| x * x * x * x, 0.0, 2.0, n_points),
+                3.0 * 16.0,
+                0.00000000001,
+            );
+        }
+    }
+
+    #[test]
+    fn test_gauss_hermite_smooth() {
+        // lognormal mean
+        assert_in_delta(
+            gauss_hermite(exp, 0.5, 0.8, 20),
+            exp(0.5 + 0.5 * 0.8 * 0.8),
+            0.000000001,
+        );
+        // probit-normal integral
+        assert_in_delta(
+            gauss_hermite(|x| Normal::cdf(x, 0.0, 1.0), 1.0, 0.5, 20),
+            Normal::cdf(1.0 / sqrt(1.25), 0.0, 1.0),
+            0.0000001,
+        );
+    }
+
+    #[test]
+    fn test_gauss_hermite_invalid() {
+        assert!(gauss_hermite(|x| x, 0.0, 1.0, 7).is_nan());
+        assert!(gauss_hermite(|x| x, 0.0, 0.0, 5).is_nan());
+        assert!(gauss_hermite(|x| x, f64::NAN, 1.0, 5).is_nan());
+    }
+
+    #[test]
+    fn test_quantile_accuracy() {
+        let probs = [1e-10, 0.001, 0.1, 0.25, 0.5, 0.75, 0.9, 0.999];
+        let mut out = [0.0; 8];
+        quantile_accuracy(&Normal::new(1.0, 2.0).unwrap(), &probs, &mut out);
+        for err in out {
+            assert!(err < 1e-12, "{}", err);
+        }
+
+        quantile_accuracy(&StudentsT::new(5.5).unwrap(), &probs, &mut out);
+        for err in out {
+            assert!(err < 1e-4, "{}", err);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "probs and out must have the same length")]
+    fn test_quantile_accuracy_different_lengths() {
+        quantile_accuracy(&Normal::new(0.0, 1.0).unwrap(), &[0.5], &mut [0.0; 2]);
+    }
+
+    #[test]
+    fn test_black_scholes() {
+        // example from Hull, Options, Futures, and Other Derivatives
+        assert_in_delta(
+            black_scholes_call(42.0, 40.0, 0.1, 0.2, 0.5),
+            4.75942239,
+            0.00000001,
+        );
+        assert_in_delta(
+            black_scholes_put(42.0, 40.0, 0.1, 0.2, 0.5),
+            0.80859937,
+            0.00000001,
+        );
+
+        assert_in_delta(
+            black_scholes_call(100.0, 100.0, 0.05, 0.2, 1.0),
+            10.45058357,
+            0.00000001,
+        );
+        assert_in_delta(
+            black_scholes_put(100.0, 100.0, 0.05, 0.2, 1.0),
+            5.57352602,
+            0.00000001,
+        );
+    }
+
+    #[test]
+    fn test_black_scholes_negative_rate() {
+        assert_in_delta(
+            black_scholes_call(100.0, 120.0, -0.01, 0.3, 2.0),
+            9.51633146,
+            0.00000001,
+        );
+        assert_in_delta(
+            black_scholes_put(100.0, 120.0, -0.01, 0.3, 2.0),
+            31.94049227,
+            0.00000001,
+        );
+    }
+
+    #[test]
+    fn test_black_scholes_put_call_parity() {
+        for (spot, strike) in [(80.0, 100.0), (100.0, 100.0), (120.0, 100.0)] {
+            let call = black_scholes_call(spot, strike, 0.03, 0.25, 0.75);
+            let put = black_scholes_put(spot, strike, 0.03, 0.25, 0.75);
+            assert_in_delta(call - put, spot - strike * exp(-0.03 * 0.75), 0.000000001);
+        }
+    }
+
+    #[test]
+    fn test_black_scholes_invalid() {
+        let invalid = [
+            (0.0, 100.0, 0.05, 0.2, 1.0),
+            (100.0, -1.0, 0.05, 0.2, 1.0),
+            (100.0, 100.0, f64::NAN, 0.2, 1.0),
+            (100.0, 100.0, 0.05, 0.0, 1.0),
+            (100.0, 100.0, 0.05, 0.2, 0.0),
+        ];
+        for (spot, strike, rate, vol, time) in invalid {
+            assert!(black_scholes_call(spot, strike, rate, vol, time).is_nan());
+            assert!(black_scholes_put(spot, strike, rate, vol, time).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_bs_delta() {
+        for spot in [50.0, 90.0, 100.0, 110.0, 200.0] {
+            let delta = bs_delta(spot, 100.0, 0.05, 0.2, 1.0);
+            assert!(delta > 0.0 && delta < 1.0);
+
+            let h = 0.0001;
+            let diff = (black_scholes_call(spot + h, 100.0, 0.05, 0.2, 1.0)
+                - black_scholes_call(spot - h, 100.0, 0.05, 0.2, 1.0))
+                / (2.0 * h);
+            assert_in_delta(delta, diff, 0.000001);
+
+            let put_diff = (black_scholes_put(spot + h, 100.0, 0.05, 0.2, 1.0)
+                - black_scholes_put(spot - h, 100.0, 0.05, 0.2, 1.0))
+                / (2.0 * h);
+            assert_in_delta(delta - 1.0, put_diff, 0.000001);
+        }
+    }
+
+    #[test]
+    fn test_bs_gamma() {
+        for spot in [50.0, 90.0, 100.0, 110.0, 200.0] {
+            let h = 0.01;
+            let diff = (black_scholes_call(spot + h, 100.0, 0.05, 0.2, 1.0)
+                - 2.0 * black_scholes_call(spot, 100.0, 0.05, 0.2, 1.0)
+                + black_scholes_call(spot - h, 100.0, 0.05, 0.2, 1.0))
+                / (h * h);
+            assert_in_delta(bs_gamma(spot, 100.0, 0.05, 0.2, 1.0), diff, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_bs_vega() {
+        for vol in [0.1, 0.2, 0.5] {
+            let h = 0.00001;
+            let diff = (black_scholes_call(100.0, 110.0, 0.05, vol + h, 0.5)
+                - black_scholes_call(100.0, 110.0, 0.05, vol - h, 0.5))
+                / (2.0 * h);
+            assert_in_delta(bs_vega(100.0, 110.0, 0.05, vol, 0.5), diff, 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_bs_greeks_invalid() {
+        assert!(bs_delta(0.0, 100.0, 0.05, 0.2, 1.0).is_nan());
+        assert!(bs_gamma(100.0, 100.0, 0.05, 0.0, 1.0).is_nan());
+        assert!(bs_vega(100.0, 100.0, 0.05, 0.2, -1.0).is_nan());
+    }
+
+    #[test]
+    fn test_implied_volatility() {
+        for strike in [80.0, 90.0, 100.0, 110.0, 125.0] {
+            for vol in [0.05, 0.2, 0.6, 1.5] {
+                let call = black_scholes_call(100.0, strike, 0.05, vol, 0.75);
+                let put = black_scholes_put(100.0, strike, 0.05, vol, 0.75);
+                assert_in_delta(
+                    implied_volatility(call, 100.0, strike, 0.05, 0.75, true),
+                    vol,
+                    0.000001,
+                );
+                assert_in_delta(
+                    implied_volatility(put, 100.0, strike, 0.05, 0.75, false),
+                    vol,
+                    0.000001,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_implied_volatility_at_bound() {
+        // price rounds to intrinsic value, so volatility is not identifiable
+        let call = black_scholes_call(100.0, 50.0, 0.05, 0.05, 0.75);
+        assert!(implied_volatility(call, 100.0, 50.0, 0.05, 0.75, true).is_nan());
+    }
+
+    #[test]
+    fn test_implied_volatility_at_the_money_forward() {
+        let strike = 100.0 * exp(0.05);
+        let call = black_scholes_call(100.0, strike, 0.05, 0.3, 1.0);
+        assert_in_delta(
+            implied_volatility(call, 100.0, strike, 0.05, 1.0, true),
+            0.3,
+            0.000001,
+        );
+    }
+
+    #[test]
+    fn test_implied_volatility_arbitrage() {
+        // call below intrinsic value and above spot
+        assert!(implied_volatility(1.0, 100.0, 90.0, 0.0, 1.0, true).is_nan());
+        assert!(implied_volatility(100.0, 100.0, 90.0, 0.0, 1.0, true).is_nan());
+        // put above discounted strike
+        assert!(implied_volatility(95.0, 100.0, 100.0, 0.1, 1.0, false).is_nan());
+    }
+
+    #[test]
+    fn test_implied_volatility_invalid() {
+        assert!(implied_volatility(10.0, 0.0, 100.0, 0.05, 1.0, true).is_nan());
+        assert!(implied_volatility(10.0, 100.0, 100.0, 0.05, 0.0, true).is_nan());
+        assert!(implied_volatility(f64::NAN, 100.0, 100.0, 0.05, 1.0, true).is_nan());
+    }
+
+    #[test]
+    fn test_regression_band() {
+        // y = [2.1, 3.9, 6.2, 7.8, 10.1, 12.2, 13.8, 16.1] fit on x = 1..8
+        let residual_std = 0.1801674705942149;
+        let inputs = [4.5, 1.0, 10.0];
+        let expected = [
+            (0.06369882010319863, 0.15586539781446815),
+            (0.11629760218995146, 0.28456998105833536),
+            (0.1656402635260596, 0.405307123848691),
+        ];
+        for (x0, (se, half_width)) in inputs.iter().zip(expected) {
+            let (act_se, act_half_width) = regression_band(*x0, 4.5, 42.0, 8, residual_std, 0.95);
+            assert_in_delta(act_se, se, 0.000000001);
+            assert_in_delta(act_half_width, half_width, 0.000001);
+        }
+    }
+
+    #[test]
+    fn test_regression_band_narrowest_at_mean() {
+        let (_, at_mean) = regression_band(4.5, 4.5, 42.0, 8, 0.2, 0.9);
+        for x0 in [0.0, 3.0, 6.0, 20.0] {
+            let (_, half_width) = regression_band(x0, 4.5, 42.0, 8, 0.2, 0.9);
+            assert!(half_width > at_mean);
+        }
+    }
+
+    #[test]
+    fn test_regression_band_invalid() {
+        let invalid = [
+            regression_band(1.0, 4.5, 42.0, 2, 0.2, 0.95),
+            regression_band(1.0, 4.5, 0.0, 8, 0.2, 0.95),
+            regression_band(1.0, 4.5, 42.0, 8, 0.0, 0.95),
+            regression_band(1.0, 4.5, 42.0, 8, 0.2, 1.0),
+            regression_band(1.0, 4.5, 42.0, 8, 0.2, 0.0),
+            regression_band(1.0, 4.5, 42.0, 8, 0.2, f64::NAN),
+            regression_band(f64::NAN, 4.5, 42.0, 8, 0.2, 0.95),
+        ];
+        for (se, half_width) in invalid {
+            assert!(se.is_nan());
+            assert!(half_width.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_cdf_via_quadrature_normal() {
+        for x in [-8.0, -3.0, -1.0, 0.0, 0.5, 2.0, 6.0] {
+            let act = cdf_via_quadrature(|x| Normal::pdf(x, 0.0, 1.0), f64::NEG_INFINITY, x);
+            assert_in_delta(act, Normal::cdf(x, 0.0, 1.0), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_cdf_via_quadrature_finite_lower() {
+        for x in [0.1, 1.0, 5.0, 20.0] {
+            let act = cdf_via_quadrature(|x| 2.0 * exp(-2.0 * x), 0.0, x);
+            assert_in_delta(act, 1.0 - exp(-2.0 * x), 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_cdf_via_quadrature_infinite_upper() {
+        assert_in_delta(
+            cdf_via_quadrature(|x| exp(-x), 0.0, f64::INFINITY),
+            1.0,
+            1e-10,
+        );
+        assert_in_delta(
+            cdf_via_quadrature(|x| StudentsT::pdf(x, 3), f64::NEG_INFINITY, f64::INFINITY),
+            1.0,
+            1e-8,
+        );
+    }
+
+    #[test]
+    fn test_cdf_via_quadrature_heavy_tail() {
+        for x in [-10.0, -1.0, 0.0, 2.0] {
+            let act = cdf_via_quadrature(|x| StudentsT::pdf(x, 3), f64::NEG_INFINITY, x);
+            assert_in_delta(act, StudentsT::cdf(x, 3), 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_cdf_via_quadrature_below_lower() {
+        assert_eq!(cdf_via_quadrature(|x| exp(-x), 0.0, 0.0), 0.0);
+        assert_eq!(cdf_via_quadrature(|x| exp(-x), 0.0, -1.0), 0.0);
+    }
+
+    #[test]
+    fn test_cdf_via_quadrature_invalid() {
+        assert!(cdf_via_quadrature(|x| exp(-x), f64::NAN, 1.0).is_nan());
+        assert!(cdf_via_quadrature(|x| exp(-x), 0.0, f64::NAN).is_nan());
+        assert!(cdf_via_quadrature(|x| exp(-x), f64::INFINITY, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_ppf_via_bisection_normal() {
+        for p in [1e-6, 0.001, 0.1, 0.3, 0.5, 0.7, 0.9, 0.999, 1.0 - 1e-6] {
+            let act = ppf_via_bisection(|x| Normal::cdf(x, 0.0, 1.0), p, -1.0, 1.0);
+            assert_in_delta(act, Normal::ppf(p, 0.0, 1.0), 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_ppf_via_bisection_expands() {
+        // bracket far from the quantiles in both directions
+        for p in [0.001, 0.5, 0.999] {
+            let act = ppf_via_bisection(|x| Cauchy::cdf(x, 0.0, 1.0), p, 1000.0, 1001.0);
+            assert_in_delta(act, Cauchy::ppf(p, 0.0, 1.0), 1e-7);
+            let act = ppf_via_bisection(|x| Cauchy::cdf(x, 0.0, 1.0), p, -1001.0, -1000.0);
+            assert_in_delta(act, Cauchy::ppf(p, 0.0, 1.0), 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_ppf_via_bisection_discrete() {
+        // smallest x with cdf(x) >= p
+        let cdf = |x: f64| {
+            if x < 1.0 {
+                0.0
+            } else if x < 2.0 {
+                0.5
+            } else {
+                1.0
+            }
+        };
+        assert_in_delta(ppf_via_bisection(cdf, 0.5, 0.0, 3.0), 1.0, 1e-12);
+        assert_in_delta(ppf_via_bisection(cdf, 0.75, 0.0, 3.0), 2.0, 1e-12);
+        assert_in_delta(ppf_via_bisection(cdf, 0.0, 0.0, 3.0), 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_ppf_via_bisection_bounded() {
+        let cdf = |x| Beta::cdf(x, 2.0, 3.0);
+        assert_in_delta(ppf_via_bisection(cdf, 0.0, 0.0, 1.0), 0.0, 1e-12);
+        // cdf rounds to 1 just below the upper end
+        assert_in_delta(ppf_via_bisection(cdf, 1.0, 0.0, 1.0), 1.0, 1e-5);
+        // bracket inside the support
+        assert_in_delta(ppf_via_bisection(cdf, 0.0, 0.4, 0.6), 0.0, 1e-12);
+        assert_in_delta(ppf_via_bisection(cdf, 1.0, 0.4, 0.6), 1.0, 1e-5);
+        assert_in_delta(
+            ppf_via_bisection(cdf, 0.5, 0.0, 1.0),
+            Beta::ppf(0.5, 2.0, 3.0),
+            1e-9,
+        );
+    }
+
+    #[test]
+    fn test_ppf_via_bisection_invalid() {
+        let cdf = |x| Normal::cdf(x, 0.0, 1.0);
+        assert!(ppf_via_bisection(cdf, -0.1, -1.0, 1.0).is_nan());
+        assert!(ppf_via_bisection(cdf, 1.1, -1.0, 1.0).is_nan());
+        assert!(ppf_via_bisection(cdf, f64::NAN, -1.0, 1.0).is_nan());
+        assert!(ppf_via_bisection(cdf, 0.5, 1.0, -1.0).is_nan());
+        assert!(ppf_via_bisection(cdf, 0.5, f64::NAN, 1.0).is_nan());
+    }
+}
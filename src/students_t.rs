@@ -1,16 +1,54 @@
-use crate::math::{atan, cos, exp, floor, log, pow, sin, sqrt, tgamma};
-use crate::Normal;
-use core::f64::consts::PI;
+use crate::beta::{continued_fraction, ln_beta, regularized, RegOpts};
+use crate::gamma::ln_gamma_sign;
+use crate::math::{acos, atan, cos, exp, fabs, floor, log, log1p, pow, sin, sqrt};
+use crate::{erfc, ContinuousDistribution, Normal};
+use core::f64::consts::{PI, SQRT_2};
 
 /// The Student's t distribution.
-pub struct StudentsT;
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StudentsT {
+    n: f64,
+}
+
+/// The branch of Hill's algorithm used by [`StudentsT::cdf_debug`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CdfBranch {
+    /// Asymptotic series for large or non-integer degrees of freedom.
+    Asymptotic,
+    /// Nested summation of the cosine series for small integer degrees of freedom.
+    CosineSeries,
+    /// Tail series for large `x` and integer degrees of freedom.
+    TailSeries,
+    /// Normal distribution for infinite degrees of freedom.
+    NormalLimit,
+    /// Regularized incomplete beta function for degrees of freedom below one.
+    IncompleteBeta,
+    /// Closed form for four degrees of freedom.
+    ClosedForm,
+    /// No computation needed, as `x` is infinite or an input is `NAN`.
+    Trivial,
+}
 
 impl StudentsT {
+    /// Creates a Student's t distribution with the given degrees of freedom.
+    ///
+    /// Returns `None` if `n` is not positive.
+    pub fn new<T: Into<f64>>(n: T) -> Option<Self> {
+        let n = n.into();
+
+        if n.is_nan() || n <= 0.0 {
+            return None;
+        }
+
+        Some(StudentsT { n })
+    }
+
     /// Returns the probability density function (PDF) of the Student's t distribution.
     pub fn pdf<T: Into<f64>>(x: f64, n: T) -> f64 {
         let n = n.into();
 
-        if n.is_nan() || n <= 0.0 {
+        if x.is_nan() || n.is_nan() || n <= 0.0 {
             return f64::NAN;
         }
 
@@ -18,36 +56,109 @@ impl StudentsT {
             return Normal::pdf(x, 0.0, 1.0);
         }
 
-        tgamma((n + 1.0) / 2.0) / (sqrt(n * PI) * tgamma(n / 2.0))
-            * pow(1.0 + x * x / n, -(n + 1.0) / 2.0)
+        // compute normalizing constant in log space to avoid overflow for large n
+        exp(ln_normalizing_constant(n)) * pow(1.0 + x * x / n, -(n + 1.0) / 2.0)
+    }
+
+    /// Returns the natural logarithm of the probability density function (PDF) of the Student's t distribution.
+    ///
+    /// This stays finite in the far tails where the PDF underflows to zero.
+    pub fn ln_pdf<T: Into<f64>>(x: f64, n: T) -> f64 {
+        let n = n.into();
+
+        if x.is_nan() || n.is_nan() || n <= 0.0 {
+            return f64::NAN;
+        }
+
+        if n == f64::INFINITY {
+            return Normal::ln_pdf(x, 0.0, 1.0);
+        }
+
+        let t = fabs(x) / sqrt(n);
+        // avoid overflow of t * t for large x
+        let ln_b = if t > 1e150 {
+            2.0 * log(t)
+        } else {
+            log1p(t * t)
+        };
+        ln_normalizing_constant(n) - (n + 1.0) / 2.0 * ln_b
     }
 
     /// Returns the cumulative distribution function (CDF) of the Student's t distribution.
-    // Hill, G. W. (1970).
-    // Algorithm 395: Student's t-distribution.
-    // Communications of the ACM, 13(10), 617-619.
     pub fn cdf<T: Into<f64>>(x: f64, n: T) -> f64 {
         let n = n.into();
 
-        // TODO support n > 0.0
-        if x.is_nan() || n.is_nan() || n < 1.0 {
+        if x.is_nan() || n.is_nan() || n <= 0.0 {
             return f64::NAN;
         }
 
+        if n == f64::INFINITY {
+            return Normal::std_cdf(x);
+        }
+
+        // tail probability from the regularized incomplete beta function,
+        // which is the same for integer and non-integer n
+        let tail = upper_tail(fabs(x), n);
+        if x < 0.0 {
+            tail
+        } else {
+            1.0 - tail
+        }
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the Student's t distribution using Hill's algorithm.
+    ///
+    /// This is faster than [`StudentsT::cdf`] but less accurate for non-integer or large `n`.
+    pub fn cdf_hill<T: Into<f64>>(x: f64, n: T) -> f64 {
+        StudentsT::cdf_with_branch(x, n.into()).0
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the Student's t distribution along with the branch of Hill's algorithm that computed it.
+    ///
+    /// This is a debugging aid for mapping out accuracy at branch boundaries. The value is the same as [`StudentsT::cdf_hill`].
+    pub fn cdf_debug<T: Into<f64>>(x: f64, n: T) -> (f64, CdfBranch) {
+        StudentsT::cdf_with_branch(x, n.into())
+    }
+
+    // Hill, G. W. (1970).
+    // Algorithm 395: Student's t-distribution.
+    // Communications of the ACM, 13(10), 617-619.
+    fn cdf_with_branch(x: f64, n: f64) -> (f64, CdfBranch) {
+        if x.is_nan() || n.is_nan() || n <= 0.0 {
+            return (f64::NAN, CdfBranch::Trivial);
+        }
+
         if x == f64::NEG_INFINITY {
-            return 0.0;
+            return (0.0, CdfBranch::Trivial);
         }
 
         if x == f64::INFINITY {
-            return 1.0;
+            return (1.0, CdfBranch::Trivial);
         }
 
         if n == f64::INFINITY {
-            return Normal::cdf(x, 0.0, 1.0);
+            return (Normal::std_cdf(x), CdfBranch::NormalLimit);
         }
 
         let (start, sign) = if x < 0.0 { (0.0, 1.0) } else { (1.0, -1.0) };
 
+        if n < 1.0 {
+            // tail probability from the regularized incomplete beta function
+            let tail = 0.5 * regularized(n / (n + x * x), 0.5 * n, 0.5).unwrap_or(f64::NAN);
+            return (start + sign * tail, CdfBranch::IncompleteBeta);
+        }
+
+        if n == 4.0 {
+            // closed form for tail probability (1 - w)^2 * (2 + w) / 4 with w = |x| / sqrt(4 + x^2)
+            let r = sqrt(4.0 + x * x);
+            let w = fabs(x) / r;
+            let v = 4.0 / (r * (r + fabs(x)));
+            return (
+                start + sign * v * v * (2.0 + w) / 4.0,
+                CdfBranch::ClosedForm,
+            );
+        }
+
         let mut z = 1.0;
         let t = x * x;
         let mut y = t / n;
@@ -67,7 +178,7 @@ impl StudentsT {
                 / b
                 + 1.0)
                 * sqrt(y);
-            return start + sign * Normal::cdf(-y, 0.0, 1.0);
+            return (start + sign * Normal::std_cdf(-y), CdfBranch::Asymptotic);
         }
 
         // make n mutable and int
@@ -95,19 +206,30 @@ impl StudentsT {
             } else {
                 (atan(y) + a / b) * (2.0 / PI)
             };
-            return start + sign * (z - a) / 2.0;
+            return (start + sign * (z - a) / 2.0, CdfBranch::CosineSeries);
         }
 
         // tail series expanation for large t-values
+        if b == f64::INFINITY {
+            // tail probability underflows
+            return (start, CdfBranch::TailSeries);
+        }
+
         let mut a = sqrt(b);
         y = a * n as f64;
         let mut j = 0;
-        while a != z {
+        // cap iterations in case the series stalls without reaching a fixed point
+        for _ in 0..1000 {
             j += 2;
-            z = a;
             y = y * (j - 1) as f64 / (b * j as f64);
-            a += y / (n + j) as f64;
+            let d = y / (n as u32 + j) as f64;
+            a += d;
+            if fabs(d) <= f64::EPSILON * fabs(a) {
+                break;
+            }
         }
+        // with z and y reset, the loop below only applies the recurrence factors,
+        // so the result is the upper tail probability itself
         z = 0.0;
         y = 0.0;
         a = -a;
@@ -122,40 +244,55 @@ impl StudentsT {
         } else {
             (atan(y) + a / b) * (2.0 / PI)
         };
-        start + sign * (z - a) / 2.0
+        (start + sign * (z - a) / 2.0, CdfBranch::TailSeries)
     }
 
     /// Returns the percent-point/quantile function (PPF) of the Student's t distribution.
-    // Hill, G. W. (1970).
-    // Algorithm 396: Student's t-quantiles.
-    // Communications of the ACM, 13(10), 619-620.
     pub fn ppf<T: Into<f64>>(p: f64, n: T) -> f64 {
         let n = n.into();
 
-        // TODO support n > 0.0
-        if !(0.0..=1.0).contains(&p) || n < 1.0 {
+        if !(0.0..=1.0).contains(&p) || n.is_nan() || n <= 0.0 {
             return f64::NAN;
         }
 
         if n == f64::INFINITY {
-            return Normal::ppf(p, 0.0, 1.0);
+            return Normal::std_ppf(p);
         }
 
-        // distribution is symmetric
-        let (sign, p) = if p < 0.5 { (-1.0, 1.0 - p) } else { (1.0, p) };
+        // distribution is symmetric, so work with the smaller tail
+        // to keep precision for p close to 0
+        let (sign, tail) = if p < 0.5 { (-1.0, p) } else { (1.0, 1.0 - p) };
 
-        // two-tail to one-tail
-        let p = 2.0 * (1.0 - p);
+        sign * StudentsT::upper_ppf(tail, n)
+    }
+
+    // Hill, G. W. (1970).
+    // Algorithm 396: Student's t-quantiles.
+    // Communications of the ACM, 13(10), 619-620.
+    // returns the quantile x >= 0 with upper tail probability `tail` <= 0.5
+    fn upper_ppf(tail: f64, n: f64) -> f64 {
+        // one-tail to two-tail
+        let p = 2.0 * tail;
 
         if n == 2.0 {
-            return sign * sqrt(2.0 / (p * (2.0 - p)) - 2.0);
+            return sqrt(2.0 / (p * (2.0 - p)) - 2.0);
+        }
+
+        if n < 1.0 {
+            return ppf_less_than_one(p, n);
         }
 
         let half_pi = PI / 2.0;
 
         if n == 1.0 {
             let p = p * half_pi;
-            return sign * cos(p) / sin(p);
+            return cos(p) / sin(p);
+        }
+
+        if n == 4.0 {
+            let a = sqrt(p * (2.0 - p));
+            let q = cos(acos(a) / 3.0) / a;
+            return 2.0 * sqrt(q - 1.0);
         }
 
         let a = 1.0 / (n - 0.5);
@@ -164,9 +301,13 @@ impl StudentsT {
         let d = ((94.5 / (b + c) - 3.0) / b + 1.0) * sqrt(a * half_pi) * n;
         let mut x = d * p;
         let mut y = pow(x, 2.0 / n);
+        if y < f64::EPSILON {
+            // leading term of the tail expansion, in log space since y may underflow
+            return refine_ppf(exp(0.5 * log(n) - log(x) / n), tail, n);
+        }
         if y > 0.05 + a {
             // asymptotic inverse expansion about normal
-            x = Normal::ppf(p * 0.5, 0.0, 1.0);
+            x = Normal::std_ppf(p * 0.5);
             y = x * x;
             if n < 5.0 {
                 c += 0.3 * (n - 4.5) * (x + 0.6);
@@ -188,14 +329,315 @@ impl StudentsT {
                 / (n + 2.0)
                 + 1.0 / y;
         }
-        sign * sqrt(n * y)
+        refine_ppf(sqrt(n * y), tail, n)
+    }
+
+    /// Returns the two-sided critical value of the Student's t distribution for significance level `alpha`.
+    ///
+    /// This is `ppf(1 - alpha / 2)`, computed without the subtraction so it stays accurate for tiny `alpha`.
+    pub fn critical_value<T: Into<f64>>(alpha: f64, n: T) -> f64 {
+        let n = n.into();
+
+        if !(0.0..=1.0).contains(&alpha) || n.is_nan() || n <= 0.0 {
+            return f64::NAN;
+        }
+
+        if n == f64::INFINITY {
+            return -Normal::std_ppf(0.5 * alpha);
+        }
+
+        StudentsT::upper_ppf(0.5 * alpha, n)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Student's t distribution.
+    ///
+    /// Alias for [`StudentsT::ppf`].
+    pub fn quantile<T: Into<f64>>(p: f64, n: T) -> f64 {
+        StudentsT::ppf(p, n)
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Student's t distribution.
+    ///
+    /// Alias for [`StudentsT::ppf`].
+    pub fn inv_cdf<T: Into<f64>>(p: f64, n: T) -> f64 {
+        StudentsT::ppf(p, n)
+    }
+
+    /// Returns the probability density function (PDF) of the Student's t distribution in single precision.
+    ///
+    /// The result is computed in `f64` and rounded to `f32`.
+    pub fn pdf_f32<T: Into<f64>>(x: f32, n: T) -> f32 {
+        StudentsT::pdf(x as f64, n) as f32
+    }
+
+    /// Returns the cumulative distribution function (CDF) of the Student's t distribution in single precision.
+    ///
+    /// The result is computed in `f64` and rounded to `f32`.
+    pub fn cdf_f32<T: Into<f64>>(x: f32, n: T) -> f32 {
+        StudentsT::cdf(x as f64, n) as f32
+    }
+
+    /// Returns the percent-point/quantile function (PPF) of the Student's t distribution in single precision.
+    ///
+    /// The result is computed in `f64` and rounded to `f32`.
+    pub fn ppf_f32<T: Into<f64>>(p: f32, n: T) -> f32 {
+        StudentsT::ppf(p as f64, n) as f32
+    }
+
+    /// Returns the mean of the Student's t distribution.
+    ///
+    /// Returns `NAN` for `n <= 1`, where the mean is undefined.
+    pub fn mean<T: Into<f64>>(n: T) -> f64 {
+        let n = n.into();
+
+        if n.is_nan() || n <= 1.0 {
+            return f64::NAN;
+        }
+
+        0.0
+    }
+
+    /// Returns the variance of the Student's t distribution.
+    ///
+    /// Returns `INFINITY` for `1 < n <= 2` and `NAN` for `n <= 1`, where the variance is undefined.
+    pub fn variance<T: Into<f64>>(n: T) -> f64 {
+        let n = n.into();
+
+        if n.is_nan() || n <= 1.0 {
+            return f64::NAN;
+        }
+
+        if n <= 2.0 {
+            return f64::INFINITY;
+        }
+
+        if n == f64::INFINITY {
+            return 1.0;
+        }
+
+        n / (n - 2.0)
+    }
+
+    /// Returns the mean of the Student's t distribution.
+    ///
+    /// Returns `None` for `0 < n <= 1`, where the mean is undefined, and `Some(NAN)` for invalid `n`.
+    pub fn try_mean<T: Into<f64>>(n: T) -> Option<f64> {
+        let n = n.into();
+
+        if n > 0.0 && n <= 1.0 {
+            return None;
+        }
+
+        Some(StudentsT::mean(n))
+    }
+
+    /// Returns the variance of the Student's t distribution.
+    ///
+    /// Returns `None` for `0 < n <= 1`, where the variance is undefined, and `Some(NAN)` for invalid `n`.
+    pub fn try_variance<T: Into<f64>>(n: T) -> Option<f64> {
+        let n = n.into();
+
+        if n > 0.0 && n <= 1.0 {
+            return None;
+        }
+
+        Some(StudentsT::variance(n))
+    }
+
+    /// Returns the skewness of the Student's t distribution.
+    ///
+    /// Returns `NAN` for `n <= 3`, where the skewness is undefined.
+    pub fn skewness<T: Into<f64>>(n: T) -> f64 {
+        let n = n.into();
+
+        if n.is_nan() || n <= 3.0 {
+            return f64::NAN;
+        }
+
+        0.0
+    }
+
+    /// Returns the excess kurtosis of the Student's t distribution.
+    ///
+    /// Returns `NAN` for `n <= 4`, where the kurtosis is undefined.
+    pub fn excess_kurtosis<T: Into<f64>>(n: T) -> f64 {
+        let n = n.into();
+
+        if n.is_nan() || n <= 4.0 {
+            return f64::NAN;
+        }
+
+        6.0 / (n - 4.0)
+    }
+}
+
+impl ContinuousDistribution for StudentsT {
+    fn pdf(&self, x: f64) -> f64 {
+        StudentsT::pdf(x, self.n)
+    }
+
+    fn cdf(&self, x: f64) -> f64 {
+        StudentsT::cdf(x, self.n)
+    }
+
+    fn ppf(&self, p: f64) -> f64 {
+        StudentsT::ppf(p, self.n)
+    }
+
+    fn ln_pdf(&self, x: f64) -> f64 {
+        StudentsT::ln_pdf(x, self.n)
     }
 }
 
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<f64> for StudentsT {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        use crate::sample::{chi_squared, standard_normal};
+
+        let z = standard_normal(rng);
+        if self.n == f64::INFINITY {
+            return z;
+        }
+
+        z / sqrt(chi_squared(self.n, rng) / self.n)
+    }
+}
+
+#[cfg(feature = "rand-distr-compat")]
+impl core::convert::TryFrom<StudentsT> for rand_distr::StudentT<f64> {
+    type Error = rand_distr::ChiSquaredError;
+
+    fn try_from(t: StudentsT) -> Result<Self, Self::Error> {
+        rand_distr::StudentT::new(t.n)
+    }
+}
+
+// returns ln(gamma((n + 1) / 2) / (gamma(n / 2) * sqrt(n * PI)))
+fn ln_normalizing_constant(n: f64) -> f64 {
+    let a = n / 2.0;
+    if a >= 20.0 {
+        // asymptotic expansion avoids cancellation between large log-gamma values
+        let a2 = a * a;
+        return -0.5 * log(2.0 * PI)
+            - (1.0 / 8.0 - (1.0 / 192.0 - (1.0 / 640.0 - 17.0 / (14336.0 * a2)) / a2) / a2) / a;
+    }
+
+    ln_gamma_sign(a + 0.5).0 - ln_gamma_sign(a).0 - 0.5 * log(n * PI)
+}
+
+// returns the upper tail probability of x >= 0
+//
+// 2 * tail is I_z(n / 2, 1 / 2) with z = n / (n + x^2), or equivalently
+// 1 - I_w(1 / 2, n / 2) with w = x^2 / (n + x^2). Each incomplete beta function
+// is evaluated where its continued fraction converges directly, so there is
+// no cancellation from the symmetry relation, and z and w are computed separately
+// so neither loses precision to 1 - w or 1 - z.
+fn upper_tail(x: f64, n: f64) -> f64 {
+    if n > 1e4 {
+        // the continued fractions lose precision as n grows, while the asymptotic
+        // series of Hill's algorithm is accurate to 1e-13 for any representable tail
+        let a = n - 0.5;
+        let b = 48.0 * a * a;
+        let y = a * log1p(x * x / n);
+        if y > 2000.0 {
+            // tail underflows, and the series is not valid this far out
+            return 0.0;
+        }
+        let y =
+            (((((-0.4 * y - 3.3) * y - 24.0) * y - 85.5) / (0.8 * y * y + 100.0 + b) + y + 3.0)
+                / b
+                + 1.0)
+                * sqrt(y);
+        // erfc keeps relative precision in the tail
+        return 0.5 * erfc(y / SQRT_2);
+    }
+
+    let a = 0.5 * n;
+    // ln(B(a, 1/2)) without the cancellation of large log-gamma values
+    let ln_b = -ln_normalizing_constant(n) - 0.5 * log(n);
+
+    if x > 1e150 {
+        // x^2 overflows, so use the leading term I_z(a, 1/2) = z^a / (a * B(a, 1/2))
+        let ln_z = log(n) - 2.0 * log(x);
+        return 0.5 * exp(a * ln_z - log(a) - ln_b);
+    }
+
+    // common factor z^a * w^(1/2) / B(a, 1/2) of both forms, with ln(z) from log1p
+    // since z is close to 1 when x^2 is small compared to n
+    let r = x * x / n;
+    let w = r / (1.0 + r);
+    let front = exp(-a * log1p(r) + 0.5 * log(w) - ln_b);
+
+    let opts = RegOpts::default();
+    let cf = if w < 1.5 / (a + 2.5) {
+        continued_fraction(w, 0.5, a, opts).map(|h| 0.5 - front * h)
+    } else {
+        continued_fraction(1.0 / (1.0 + r), a, 0.5, opts).map(|h| 0.5 * front * h / a)
+    };
+    cf.unwrap_or(f64::NAN)
+}
+
+// refines a quantile x > 0 with lower tail probability `tail` using Newton's method,
+// which removes the error of the approximations, especially in the far tails
+fn refine_ppf(mut x: f64, tail: f64, n: f64) -> f64 {
+    for _ in 0..10 {
+        let cdf = StudentsT::cdf(-x, n);
+        if cdf == 0.0 || cdf.is_nan() {
+            break;
+        }
+
+        // ratio of cdf to pdf in log space since the pdf underflows first
+        let step = (1.0 - tail / cdf) * exp(log(cdf) - StudentsT::ln_pdf(-x, n));
+        x += step;
+        if fabs(step) <= 4.0 * f64::EPSILON * x {
+            break;
+        }
+    }
+    x
+}
+
+// inverts the two-tailed probability I_z(n / 2, 1 / 2) with z = n / (n + t^2)
+// by bisection on ln(z), since the tails are too heavy for the approximations above
+fn ppf_less_than_one(p: f64, n: f64) -> f64 {
+    if p == 0.0 {
+        return f64::INFINITY;
+    }
+
+    if p == 1.0 {
+        return 0.0;
+    }
+
+    let mut lo = log(f64::MIN_POSITIVE);
+    let mut hi = 0.0;
+
+    let a = 0.5 * n;
+    if matches!(regularized(f64::MIN_POSITIVE, a, 0.5), Some(v) if p < v) {
+        // leading term I_z(a, 1/2) = z^a / (a * B(a, 1/2)) for tiny z
+        let ln_z = (log(p) + log(a) + ln_beta(a, 0.5).unwrap_or(f64::NAN)) / a;
+        return exp(0.5 * (log(n) - ln_z));
+    }
+
+    for _ in 0..100 {
+        let mid = 0.5 * (lo + hi);
+        match regularized(exp(mid), a, 0.5) {
+            Some(v) if v < p => lo = mid,
+            Some(_) => hi = mid,
+            None => return f64::NAN,
+        }
+    }
+
+    let z = exp(0.5 * (lo + hi));
+    sqrt(n * (1.0 - z) / z)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::StudentsT;
-    use core::f64::{INFINITY, NEG_INFINITY};
+    use super::{CdfBranch, StudentsT};
+    use crate::math::exp;
+    use crate::ContinuousDistribution;
+    use crate::Normal;
+    const INFINITY: f64 = f64::INFINITY;
+    const NEG_INFINITY: f64 = f64::NEG_INFINITY;
 
     fn assert_in_delta(act: f64, exp: f64, delta: f64) {
         if exp.is_finite() {
@@ -205,7 +647,12 @@ mod tests {
         }
     }
 
+    fn assert_in_relative_delta(act: f64, exp: f64, delta: f64) {
+        assert!(((exp - act) / exp).abs() < delta, "{} != {}", act, exp);
+    }
+
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_pdf_one() {
         let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
         let expected = [
@@ -271,17 +718,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pdf_large() {
+        for x in [-3.0, -1.0, 0.0, 0.5, 2.0] {
+            assert_in_delta(StudentsT::pdf(x, 5000), Normal::pdf(x, 0.0, 1.0), 0.0001);
+        }
+        assert_in_delta(StudentsT::pdf(0.0, 2000), 0.39889, 0.00001);
+        assert_in_delta(StudentsT::pdf(0.0, 1e10), 0.39894, 0.00001);
+    }
+
     #[test]
     fn test_pdf_nan() {
         assert!(StudentsT::pdf(f64::NAN, 1).is_nan());
         assert!(StudentsT::pdf(0.0, f64::NAN).is_nan());
     }
 
+    #[test]
+    fn test_pdf_nan_x() {
+        for n in [0.5, 1.0, 5.0, 1e6, INFINITY] {
+            assert!(StudentsT::pdf(f64::NAN, n).is_nan());
+            assert!(StudentsT::pdf_f32(f32::NAN, n).is_nan());
+            assert!(StudentsT::ln_pdf(f64::NAN, n).is_nan());
+        }
+    }
+
     #[test]
     fn test_pdf_zero_n() {
         assert!(StudentsT::pdf(0.5, 0).is_nan());
     }
 
+    #[test]
+    fn test_ln_pdf() {
+        for n in [0.5, 1.0, 2.0, 2.5, 4.0, 30.0, 1000.0] {
+            for x in [-5.0, -1.0, 0.0, 0.5, 3.0] {
+                assert_in_delta(
+                    exp(StudentsT::ln_pdf(x, n)),
+                    StudentsT::pdf(x, n),
+                    0.000000000001,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_ln_pdf_tail() {
+        assert_in_relative_delta(
+            StudentsT::ln_pdf(1e6, 3),
+            -54.065706504150384,
+            0.000000000001,
+        );
+        assert_in_relative_delta(
+            StudentsT::ln_pdf(1e200, 3),
+            -1840.871738667524,
+            0.000000000001,
+        );
+        assert_in_relative_delta(
+            StudentsT::ln_pdf(50.0, 1e6),
+            -1249.3602865169034,
+            0.0000000001,
+        );
+        assert_in_relative_delta(
+            StudentsT::ln_pdf(0.0, 1e10),
+            -0.9189385332296728,
+            0.0000000001,
+        );
+        assert_eq!(StudentsT::ln_pdf(INFINITY, 3), NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_ln_pdf_infinity() {
+        assert_eq!(
+            StudentsT::ln_pdf(1.5, INFINITY),
+            Normal::ln_pdf(1.5, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_ln_pdf_invalid() {
+        assert!(StudentsT::ln_pdf(f64::NAN, 3).is_nan());
+        assert!(StudentsT::ln_pdf(0.0, f64::NAN).is_nan());
+        assert!(StudentsT::ln_pdf(0.0, 0).is_nan());
+    }
+
     #[test]
     fn test_cdf_one() {
         let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
@@ -304,6 +822,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cdf_four() {
+        let inputs = [
+            NEG_INFINITY,
+            -100.0,
+            -10.0,
+            -3.0,
+            -1.0,
+            -0.5,
+            0.0,
+            0.5,
+            1.0,
+            2.0,
+            3.0,
+            10.0,
+            INFINITY,
+        ];
+        let expected = [
+            0.0,
+            2.998001049496231e-8,
+            0.0002810018113579956,
+            0.019970984035859414,
+            0.18695048315002944,
+            0.32166498159093164,
+            0.5,
+            0.6783350184090684,
+            0.8130495168499706,
+            0.9419417382415922,
+            0.9800290159641406,
+            0.999718998188642,
+            1.0,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(StudentsT::cdf(*input, 4), exp, 0.000000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_four_tail() {
+        let act = StudentsT::cdf(-100.0, 4);
+        assert!((act - 2.998001049496231e-8).abs() / act < 0.000000000001);
+    }
+
     #[test]
     fn test_cdf_thirty() {
         let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
@@ -326,6 +887,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cdf_non_integer_accuracy() {
+        let inputs = [-0.5, -2.0, -10.0];
+        let expected = [
+            (
+                1.5,
+                [0.34028355533497, 0.11209416517802553, 0.011829677556810778],
+            ),
+            (
+                2.5,
+                [0.32884895993485735, 0.078695747878983, 0.002220747883653712],
+            ),
+            (
+                7.3,
+                [
+                    0.3158967634022208,
+                    0.041970519667051606,
+                    8.050127600740506e-06,
+                ],
+            ),
+        ];
+        for (n, exp) in expected {
+            for (input, exp) in inputs.iter().zip(exp) {
+                assert_in_relative_delta(StudentsT::cdf(*input, n), exp, 0.00000000000001);
+                assert_in_delta(StudentsT::cdf(-input, n), 1.0 - exp, 0.000000000000001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cdf_large_n() {
+        let inputs = [-1.0, -5.0, -20.0];
+        let expected = [
+            (
+                9999.0,
+                [
+                    0.1586673533751287,
+                    2.9151682051141595e-07,
+                    1.3828538137878668e-87,
+                ],
+            ),
+            (
+                1e6,
+                [
+                    0.15865537491678908,
+                    2.866998935445371e-07,
+                    2.866543523695186e-89,
+                ],
+            ),
+            (
+                1e10,
+                [
+                    0.1586552539435556,
+                    2.8665157671103237e-07,
+                    2.7536351881296547e-89,
+                ],
+            ),
+        ];
+        for (n, exp) in expected {
+            for (input, exp) in inputs.iter().zip(exp) {
+                assert_in_relative_delta(StudentsT::cdf(*input, n), exp, 0.0000000000001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cdf_hill() {
+        let inputs = [-40.0, -10.0, -3.0, -1.0, -0.1, 0.0, 0.5, 2.0, 5.0, 20.0];
+        for n in 1..=200 {
+            for x in inputs {
+                let exp = StudentsT::cdf(x, n);
+                let act = StudentsT::cdf_hill(x, n);
+                assert!(
+                    (act - exp).abs() <= 1e-9 * exp + 1e-15,
+                    "x = {}, n = {}: {} != {}",
+                    x,
+                    n,
+                    act,
+                    exp
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_cdf_infinity() {
         let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
@@ -337,6 +982,65 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cdf_less_than_one() {
+        let inputs = [NEG_INFINITY, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, INFINITY];
+        let expected = [
+            [
+                0.0, 0.37385, 0.38918, 0.41633, 0.5, 0.58367, 0.61082, 0.62615, 1.0,
+            ],
+            [
+                0.0, 0.18365, 0.22276, 0.30112, 0.5, 0.69888, 0.77724, 0.81635, 1.0,
+            ],
+            [
+                0.0, 0.11332, 0.15802, 0.25712, 0.5, 0.74288, 0.84198, 0.88668, 1.0,
+            ],
+        ];
+        for (n, expected) in [0.1, 0.5, 0.9].iter().zip(expected) {
+            for (input, exp) in inputs.iter().zip(expected) {
+                assert_in_delta(StudentsT::cdf(*input, *n), exp, 0.00001);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cdf_tail_series() {
+        let inputs = [
+            (10.0, 3),
+            (10.0, 5),
+            (50.0, 5),
+            (30.0, 6),
+            (8.0, 19),
+            (1000.0, 3),
+        ];
+        let expected = [
+            0.0010641995292070751,
+            8.54737878714818e-5,
+            3.0238788133006125e-8,
+            4.549573522221688e-8,
+            8.379773441409069e-8,
+            1.1026538212882963e-9,
+        ];
+        for ((x, n), exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(StudentsT::cdf(-x, *n), exp, 0.000000000001);
+            assert_in_delta(StudentsT::cdf(*x, *n), 1.0 - exp, 0.000000000000001);
+        }
+    }
+
+    #[test]
+    fn test_cdf_large_x() {
+        assert_in_delta(StudentsT::cdf(1e15, 1), 1.0, 0.000000000000001);
+        assert_in_delta(StudentsT::cdf(-1e15, 1), 3.183098861837907e-16, 1e-25);
+        assert_eq!(StudentsT::cdf(1e200, 1), 1.0);
+        assert_in_relative_delta(
+            StudentsT::cdf(-1e200, 1),
+            3.183098861837907e-201,
+            0.000000000001,
+        );
+        assert_eq!(StudentsT::cdf(1e200, 7), 1.0);
+        assert_eq!(StudentsT::cdf(-1e200, 7), 0.0);
+    }
+
     #[test]
     fn test_cdf_nan() {
         assert!(StudentsT::cdf(f64::NAN, 1.0).is_nan());
@@ -348,6 +1052,39 @@ mod tests {
         assert!(StudentsT::cdf(0.5, 0).is_nan());
     }
 
+    #[test]
+    fn test_cdf_debug_branches() {
+        let cases = [
+            (0.5, 1.5, CdfBranch::Asymptotic),
+            (0.5, 30.0, CdfBranch::Asymptotic),
+            (50.0, 300.0, CdfBranch::Asymptotic),
+            (1.5, 3.0, CdfBranch::CosineSeries),
+            (-1.0, 1.0, CdfBranch::CosineSeries),
+            (2.5, 3.0, CdfBranch::TailSeries),
+            (10.0, 30.0, CdfBranch::TailSeries),
+            (1e200, 3.0, CdfBranch::TailSeries),
+            (1.0, f64::INFINITY, CdfBranch::NormalLimit),
+            (1.0, 0.5, CdfBranch::IncompleteBeta),
+            (1.0, 4.0, CdfBranch::ClosedForm),
+            (f64::INFINITY, 3.0, CdfBranch::Trivial),
+            (f64::NAN, 3.0, CdfBranch::Trivial),
+            (1.0, 0.0, CdfBranch::Trivial),
+        ];
+        for (x, n, branch) in cases {
+            assert_eq!(StudentsT::cdf_debug(x, n).1, branch, "x = {}, n = {}", x, n);
+        }
+    }
+
+    #[test]
+    fn test_cdf_debug_value() {
+        for n in [0.5, 1.0, 1.5, 3.0, 4.0, 30.0, 300.0, f64::INFINITY] {
+            for x in [-50.0, -2.5, -1.0, 0.0, 0.5, 2.0, 10.0] {
+                let act = StudentsT::cdf_debug(x, n).0;
+                assert_eq!(act, StudentsT::cdf_hill(x, n), "x = {}, n = {}", x, n);
+            }
+        }
+    }
+
     #[test]
     fn test_ppf_one() {
         let inputs = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
@@ -390,6 +1127,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ppf_four() {
+        let inputs = [0.0, 0.001, 0.1, 0.3, 0.5, 0.6, 0.9, 0.975, 0.999999, 1.0];
+        let expected = [
+            NEG_INFINITY,
+            -7.173182219782308,
+            -1.533206274058944,
+            -0.5686490630497054,
+            0.0,
+            0.2707222947075974,
+            1.533206274058944,
+            2.7764451051977944,
+            41.577854150450975,
+            INFINITY,
+        ];
+        for (input, exp) in inputs.iter().zip(expected) {
+            assert_in_delta(StudentsT::ppf(*input, 4), exp, 0.000000001);
+        }
+    }
+
     #[test]
     fn test_ppf_thirty() {
         let inputs = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
@@ -453,6 +1210,77 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ppf_less_than_one() {
+        let inputs = [0.6, 0.75, 0.9, 0.99];
+        let expected = [
+            [1.512976, 168.2361, 1604425.7, 1.6044257e16],
+            [0.3979754, 1.553774, 10.27032, 1028.491],
+            [0.3329776, 1.047596, 3.473846, 45.93525],
+        ];
+        for (n, expected) in [0.1, 0.5, 0.9].iter().zip(expected) {
+            for (input, exp) in inputs.iter().zip(expected) {
+                assert_in_relative_delta(StudentsT::ppf(*input, *n), exp, 0.00001);
+                assert_in_relative_delta(StudentsT::ppf(1.0 - *input, *n), -exp, 0.00001);
+            }
+        }
+        assert_eq!(StudentsT::ppf(0.0, 0.5), NEG_INFINITY);
+        assert_eq!(StudentsT::ppf(0.5, 0.5), 0.0);
+        assert_eq!(StudentsT::ppf(1.0, 0.5), INFINITY);
+    }
+
+    #[test]
+    fn test_ppf_less_than_one_round_trip() {
+        for x in [-100.0, -10.0, -2.0, -0.5, 0.5, 2.0, 10.0, 100.0] {
+            assert_in_relative_delta(StudentsT::ppf(StudentsT::cdf(x, 0.5), 0.5), x, 0.000001);
+        }
+    }
+
+    #[test]
+    fn test_ppf_extreme() {
+        let inputs = [
+            (1e-10, 3.0),
+            (1e-15, 3.0),
+            (1e-300, 3.0),
+            (1e-50, 5.0),
+            (1e-300, 10.0),
+            (1e-100, 1.0),
+            (1e-300, 2.0),
+            (1e-300, 4.0),
+            (1e-100, 0.5),
+        ];
+        let expected = [
+            -2225.769284683093,
+            -103311.08359284989,
+            -1.033110836044653e100,
+            -15683925590.993378,
+            -2.564525718948198e30,
+            -3.183098861837907e99,
+            -7.071067811865475e149,
+            -1.3160740129524925e75,
+            -1.02849115631634e199,
+        ];
+        for ((p, n), exp) in inputs.iter().zip(expected) {
+            assert_in_relative_delta(StudentsT::ppf(*p, *n), exp, 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ppf_extreme_round_trip() {
+        for n in [3, 5, 7, 10, 19] {
+            for p in [1e-300, 1e-100, 1e-50, 1e-15, 1e-10, 1e-5] {
+                assert_in_relative_delta(StudentsT::cdf(StudentsT::ppf(p, n), n), p, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ppf_extreme_overflow() {
+        // quantile exceeds f64::MAX
+        assert_eq!(StudentsT::ppf(1e-300, 0.5), NEG_INFINITY);
+        assert!(StudentsT::ppf(1e-300, 1.5).is_finite());
+    }
+
     #[test]
     fn test_ppf_nan() {
         assert!(StudentsT::ppf(f64::NAN, 1.0).is_nan());
@@ -468,4 +1296,223 @@ mod tests {
     fn test_ppf_zero_n() {
         assert!(StudentsT::ppf(0.5, 0).is_nan());
     }
+
+    #[test]
+    fn test_ppf_aliases() {
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(StudentsT::quantile(p, 2.5), StudentsT::ppf(p, 2.5));
+            assert_eq!(StudentsT::inv_cdf(p, 2.5), StudentsT::ppf(p, 2.5));
+        }
+    }
+
+    #[test]
+    fn test_critical_value() {
+        let expected = [
+            (1.0, [636619772367.5813, 12.706204736174705]),
+            (2.5, [72976.93421841858, 3.574654842003683]),
+            (5.0, [452.53922433340074, 2.5705818356363155]),
+            (30.0, [11.722008426303951, 2.042272456301238]),
+        ];
+        for (n, exp) in expected {
+            for (alpha, exp) in [1e-12, 0.05].iter().zip(exp) {
+                assert_in_relative_delta(StudentsT::critical_value(*alpha, n), exp, 1e-13);
+            }
+        }
+    }
+
+    #[test]
+    fn test_critical_value_ppf() {
+        for n in [0.5, 2.0, 4.0, 7.3] {
+            for alpha in [0.01, 0.05, 0.1, 0.5] {
+                assert_in_relative_delta(
+                    StudentsT::critical_value(alpha, n),
+                    StudentsT::ppf(1.0 - alpha / 2.0, n),
+                    0.000000000001,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_critical_value_edges() {
+        assert_eq!(StudentsT::critical_value(0.0, 5), INFINITY);
+        assert_eq!(StudentsT::critical_value(1.0, 5), 0.0);
+        assert_in_relative_delta(
+            StudentsT::critical_value(1e-12, INFINITY),
+            7.130506848171325,
+            1e-14,
+        );
+    }
+
+    #[test]
+    fn test_critical_value_invalid() {
+        assert!(StudentsT::critical_value(-0.1, 5).is_nan());
+        assert!(StudentsT::critical_value(1.1, 5).is_nan());
+        assert!(StudentsT::critical_value(f64::NAN, 5).is_nan());
+        assert!(StudentsT::critical_value(0.05, 0).is_nan());
+        assert!(StudentsT::critical_value(0.05, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_mean() {
+        assert!(StudentsT::mean(0.5).is_nan());
+        assert!(StudentsT::mean(1).is_nan());
+        assert_eq!(StudentsT::mean(1.5), 0.0);
+        assert_eq!(StudentsT::mean(INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_variance() {
+        assert!(StudentsT::variance(1).is_nan());
+        assert_eq!(StudentsT::variance(1.5), INFINITY);
+        assert_eq!(StudentsT::variance(2), INFINITY);
+        assert_eq!(StudentsT::variance(4), 2.0);
+        assert_eq!(StudentsT::variance(12), 1.2);
+        assert_eq!(StudentsT::variance(INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_try_mean() {
+        assert_eq!(StudentsT::try_mean(0.5), None);
+        assert_eq!(StudentsT::try_mean(1.0), None);
+        assert_eq!(StudentsT::try_mean(1.5), Some(0.0));
+        assert_eq!(StudentsT::try_mean(INFINITY), Some(0.0));
+    }
+
+    #[test]
+    fn test_try_mean_invalid() {
+        assert!(StudentsT::try_mean(0).unwrap().is_nan());
+        assert!(StudentsT::try_mean(-1).unwrap().is_nan());
+        assert!(StudentsT::try_mean(f64::NAN).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_try_variance() {
+        assert_eq!(StudentsT::try_variance(0.5), None);
+        assert_eq!(StudentsT::try_variance(1), None);
+        assert_eq!(StudentsT::try_variance(1.5), Some(INFINITY));
+        assert_eq!(StudentsT::try_variance(4), Some(2.0));
+        assert_eq!(StudentsT::try_variance(INFINITY), Some(1.0));
+    }
+
+    #[test]
+    fn test_try_variance_invalid() {
+        assert!(StudentsT::try_variance(0).unwrap().is_nan());
+        assert!(StudentsT::try_variance(-1).unwrap().is_nan());
+        assert!(StudentsT::try_variance(f64::NAN).unwrap().is_nan());
+    }
+
+    #[test]
+    fn test_skewness() {
+        assert!(StudentsT::skewness(2).is_nan());
+        assert!(StudentsT::skewness(3).is_nan());
+        assert_eq!(StudentsT::skewness(3.5), 0.0);
+    }
+
+    #[test]
+    fn test_excess_kurtosis() {
+        assert!(StudentsT::excess_kurtosis(3).is_nan());
+        assert!(StudentsT::excess_kurtosis(4).is_nan());
+        assert_eq!(StudentsT::excess_kurtosis(5), 6.0);
+        assert_eq!(StudentsT::excess_kurtosis(10), 1.0);
+        assert_eq!(StudentsT::excess_kurtosis(INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_moments_nan() {
+        assert!(StudentsT::mean(f64::NAN).is_nan());
+        assert!(StudentsT::variance(f64::NAN).is_nan());
+        assert!(StudentsT::skewness(f64::NAN).is_nan());
+        assert!(StudentsT::excess_kurtosis(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_new() {
+        assert!(StudentsT::new(2.5).is_some());
+        assert!(StudentsT::new(INFINITY).is_some());
+        assert!(StudentsT::new(0).is_none());
+        assert!(StudentsT::new(-1.0).is_none());
+        assert!(StudentsT::new(f64::NAN).is_none());
+    }
+
+    #[test]
+    fn test_continuous_distribution() {
+        let dist = StudentsT::new(2.5).unwrap();
+        for x in [-2.0, 0.0, 0.5, 3.0] {
+            assert_eq!(
+                ContinuousDistribution::pdf(&dist, x),
+                StudentsT::pdf(x, 2.5)
+            );
+            assert_eq!(
+                ContinuousDistribution::cdf(&dist, x),
+                StudentsT::cdf(x, 2.5)
+            );
+        }
+        for p in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(
+                ContinuousDistribution::ppf(&dist, p),
+                StudentsT::ppf(p, 2.5)
+            );
+        }
+    }
+
+    #[test]
+    fn test_f32() {
+        let inputs = [-3.0, -1.0, 0.0, 1.0, 3.0];
+        for input in inputs {
+            let x = input as f32;
+            assert_eq!(
+                StudentsT::pdf_f32(x, 2.5),
+                StudentsT::pdf(input, 2.5) as f32
+            );
+            assert_eq!(
+                StudentsT::cdf_f32(x, 2.5),
+                StudentsT::cdf(input, 2.5) as f32
+            );
+        }
+        let p = 0.975_f32;
+        assert_eq!(StudentsT::ppf_f32(p, 1), StudentsT::ppf(p as f64, 1) as f32);
+        assert!(StudentsT::ppf_f32(1.5, 1).is_nan());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = StudentsT::new(10).unwrap();
+        let n = 500000;
+        let mut moments = [0.0; 4];
+        for _ in 0..n {
+            let x = dist.sample(&mut rng);
+            let mut p = x;
+            for m in moments.iter_mut() {
+                *m += p;
+                p *= x;
+            }
+        }
+        let [m1, m2, _, m4] = moments.map(|m| m / n as f64);
+        assert_in_delta(m1, 0.0, 0.01);
+        assert_in_delta(m2, StudentsT::variance(10), 0.02);
+        // excess kurtosis is 6 / (n - 4)
+        assert_in_delta(m4 / (m2 * m2) - 3.0, StudentsT::excess_kurtosis(10), 0.2);
+    }
+
+    #[cfg(feature = "rand-distr-compat")]
+    #[test]
+    fn test_rand_distr() {
+        use core::convert::TryFrom;
+        use rand::rngs::SmallRng;
+        use rand::SeedableRng;
+        use rand_distr::Distribution;
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let dist = rand_distr::StudentT::try_from(StudentsT::new(5).unwrap()).unwrap();
+        let n = 100000;
+        let mean = (0..n).map(|_| dist.sample(&mut rng)).sum::<f64>() / n as f64;
+        assert_in_delta(mean, StudentsT::mean(5), 0.02);
+    }
 }